@@ -0,0 +1,372 @@
+// Background key-caching agent (modeled on rbw's agent): a long-lived
+// process that holds an already-unlocked vault key in locked memory behind
+// a Unix-domain socket, so ordinary commands don't have to pay the Argon2id
+// cost (and re-prompt the user) on every invocation.
+// `db::Vault::open_cached_or_prompt` is the client side of this; `util::prompt_password` is still the fallback
+// whenever the agent isn't running or has no cached key.
+//
+// The client never receives the raw DEK back over the socket: every
+// encrypt/decrypt/wrap is mediated here, and only the plaintext, sealed
+// blob, or wrapped-DEK blob the caller actually asked for crosses the wire.
+//
+// Wire format: each message is a 4-byte little-endian length prefix followed
+// by that many bytes of JSON (`Request`/`Response`) - simple and easy to
+// extend with new verbs without breaking old clients (unknown fields are
+// just never read). Both directions zeroize their raw message buffer right
+// after use, since requests/responses can carry a password, a decrypted
+// item, or a KEK in transit.
+use anyhow::{Result, anyhow, bail};
+use serde::{Serialize, Deserialize};
+use std::io::{Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use zeroize::Zeroize;
+
+use crate::crypto::{CipherSuite, EncryptedBlob};
+use crate::locked::{LockedKey, LockedVec};
+
+const SOCKET_NAME: &str = "sesame-agent.sock";
+const MAX_MESSAGE_BYTES: u32 = 1024 * 1024;
+
+#[derive(Serialize, Deserialize, Debug)]
+enum Request {
+    Unlock { vault_path: String, password: String },
+    Lock,
+    // Encrypt/decrypt/wrap under whatever key is cached for `vault_path`.
+    // The DEK itself never appears in a `Response` - only the result of
+    // applying it to the bytes the caller sent.
+    Encrypt { vault_path: String, suite_id: u8, plaintext: Vec<u8> },
+    Decrypt { vault_path: String, blob: Vec<u8> },
+    WrapDek { vault_path: String, kek: [u8; 32] },
+    Status,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum Response {
+    Ok,
+    // An encoded `EncryptedBlob` (from Encrypt or WrapDek).
+    Blob { bytes: Vec<u8> },
+    Plaintext { bytes: Vec<u8> },
+    Locked,
+    Status { unlocked_vault: Option<String> },
+    Error { message: String },
+}
+
+fn socket_path() -> Result<PathBuf> {
+    let dir = std::env::var("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| std::env::temp_dir());
+    Ok(dir.join(SOCKET_NAME))
+}
+
+// Canonicalize so the same vault reached via different relative paths
+// still hits the same cache entry; falls back to the given path verbatim
+// if the file doesn't exist yet (e.g. `init`).
+fn canonical_path(db_path: &str) -> String {
+    std::fs::canonicalize(db_path)
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| db_path.to_string())
+}
+
+fn write_message<W: Write, T: Serialize>(w: &mut W, msg: &T) -> Result<()> {
+    let mut data = serde_json::to_vec(msg)?;
+    if data.len() as u64 > MAX_MESSAGE_BYTES as u64 {
+        data.zeroize();
+        bail!("agent message too large ({} bytes)", data.len());
+    }
+    let result = (|| -> Result<()> {
+        w.write_all(&(data.len() as u32).to_le_bytes())?;
+        w.write_all(&data)?;
+        w.flush()?;
+        Ok(())
+    })();
+    data.zeroize();
+    result
+}
+
+fn read_message<R: Read, T: serde::de::DeserializeOwned>(r: &mut R) -> Result<T> {
+    let mut len_buf = [0u8; 4];
+    r.read_exact(&mut len_buf)?;
+    let len = u32::from_le_bytes(len_buf);
+    if len > MAX_MESSAGE_BYTES {
+        bail!("agent message too large ({len} bytes)");
+    }
+    let mut buf = vec![0u8; len as usize];
+    r.read_exact(&mut buf)?;
+    let result = serde_json::from_slice(&buf).map_err(Into::into);
+    buf.zeroize();
+    result
+}
+
+// Zeroize whichever field of a `Request`/`Response` carries a secret, once
+// it's been written to (or read from) the socket and is no longer needed.
+fn zeroize_request(req: &mut Request) {
+    match req {
+        Request::Unlock { password, .. } => password.zeroize(),
+        Request::Encrypt { plaintext, .. } => plaintext.zeroize(),
+        Request::WrapDek { kek, .. } => kek.zeroize(),
+        Request::Lock | Request::Decrypt { .. } | Request::Status => {}
+    }
+}
+
+fn zeroize_response(resp: &mut Response) {
+    match resp {
+        Response::Blob { bytes } | Response::Plaintext { bytes } => bytes.zeroize(),
+        Response::Ok | Response::Locked | Response::Status { .. } | Response::Error { .. } => {}
+    }
+}
+
+/* --- client side: used by `db::Vault::open_cached_or_prompt` and the `agent` command --- */
+
+// Probe whether a running agent already has a key cached for `db_path`,
+// without ever asking for the key itself - `db::VaultKey::Agent` is enough
+// to let every later encrypt/decrypt go through the agent on demand.
+pub fn has_cached_key(db_path: &str) -> bool {
+    let vault_path = canonical_path(db_path);
+    let Ok(socket) = socket_path() else { return false };
+    let Ok(mut stream) = UnixStream::connect(socket) else { return false };
+    if write_message(&mut stream, &Request::Status).is_err() {
+        return false;
+    }
+    matches!(
+        read_message(&mut stream),
+        Ok(Response::Status { unlocked_vault: Some(p) }) if p == vault_path
+    )
+}
+
+// Ask a running agent to encrypt `plaintext` under whatever it has cached
+// for `db_path`. Used by `db::VaultKey::Agent` so callers never touch the
+// raw DEK themselves.
+pub fn encrypt(db_path: &str, suite: CipherSuite, plaintext: &[u8]) -> Result<EncryptedBlob> {
+    let vault_path = canonical_path(db_path);
+    let mut stream = UnixStream::connect(socket_path()?)
+        .map_err(|e| anyhow!("agent is not running: {e}"))?;
+    let mut req = Request::Encrypt { vault_path, suite_id: suite.alg_id(), plaintext: plaintext.to_vec() };
+    let sent = write_message(&mut stream, &req);
+    zeroize_request(&mut req);
+    sent?;
+    match read_message(&mut stream)? {
+        Response::Blob { bytes } => EncryptedBlob::decode(&bytes),
+        Response::Locked => bail!("agent has no cached key for this vault"),
+        Response::Error { message } => Err(anyhow!(message)),
+        _ => bail!("unexpected agent response"),
+    }
+}
+
+// Ask a running agent to decrypt `blob` under whatever it has cached for
+// `db_path`, returning only the plaintext.
+pub fn decrypt(db_path: &str, blob: &EncryptedBlob) -> Result<LockedVec> {
+    let vault_path = canonical_path(db_path);
+    let mut stream = UnixStream::connect(socket_path()?)
+        .map_err(|e| anyhow!("agent is not running: {e}"))?;
+    write_message(&mut stream, &Request::Decrypt { vault_path, blob: blob.encode() })?;
+    match read_message(&mut stream)? {
+        Response::Plaintext { bytes } => Ok(LockedVec::from_vec(bytes)),
+        Response::Locked => bail!("agent has no cached key for this vault"),
+        Response::Error { message } => Err(anyhow!(message)),
+        _ => bail!("unexpected agent response"),
+    }
+}
+
+// Ask a running agent to wrap a freshly derived KEK around the DEK it has
+// cached for `db_path` - used to add or rotate a key slot without the DEK
+// ever leaving the agent process.
+pub fn wrap_dek(db_path: &str, kek: &[u8; 32]) -> Result<EncryptedBlob> {
+    let vault_path = canonical_path(db_path);
+    let mut stream = UnixStream::connect(socket_path()?)
+        .map_err(|e| anyhow!("agent is not running: {e}"))?;
+    let mut req = Request::WrapDek { vault_path, kek: *kek };
+    let sent = write_message(&mut stream, &req);
+    zeroize_request(&mut req);
+    sent?;
+    match read_message(&mut stream)? {
+        Response::Blob { bytes } => EncryptedBlob::decode(&bytes),
+        Response::Locked => bail!("agent has no cached key for this vault"),
+        Response::Error { message } => Err(anyhow!(message)),
+        _ => bail!("unexpected agent response"),
+    }
+}
+
+// Best-effort: tell a running agent to derive and cache the key for
+// `vault_path` under `password`, so the next command skips the prompt. Does
+// nothing if no agent is listening.
+pub fn cache_unlocked(db_path: &str, password: &str) {
+    let vault_path = canonical_path(db_path);
+    let Ok(socket) = socket_path() else { return };
+    let Ok(mut stream) = UnixStream::connect(socket) else { return };
+    let mut req = Request::Unlock { vault_path, password: password.to_string() };
+    let _ = write_message(&mut stream, &req);
+    zeroize_request(&mut req);
+    let _: Result<Response> = read_message(&mut stream);
+}
+
+pub fn lock() -> Result<()> {
+    let mut stream = UnixStream::connect(socket_path()?)
+        .map_err(|e| anyhow!("agent is not running: {e}"))?;
+    write_message(&mut stream, &Request::Lock)?;
+    match read_message(&mut stream)? {
+        Response::Ok => Ok(()),
+        Response::Error { message } => Err(anyhow!(message)),
+        _ => bail!("unexpected agent response"),
+    }
+}
+
+pub fn unlock(db_path: &str, password: &str) -> Result<()> {
+    let vault_path = canonical_path(db_path);
+    let mut stream = UnixStream::connect(socket_path()?)
+        .map_err(|e| anyhow!("agent is not running: {e}"))?;
+    let mut req = Request::Unlock { vault_path, password: password.to_string() };
+    let sent = write_message(&mut stream, &req);
+    zeroize_request(&mut req);
+    sent?;
+    match read_message(&mut stream)? {
+        Response::Ok => Ok(()),
+        Response::Error { message } => Err(anyhow!(message)),
+        _ => bail!("unexpected agent response"),
+    }
+}
+
+pub fn status() -> Result<Option<String>> {
+    let mut stream = UnixStream::connect(socket_path()?)
+        .map_err(|e| anyhow!("agent is not running: {e}"))?;
+    write_message(&mut stream, &Request::Status)?;
+    match read_message(&mut stream)? {
+        Response::Status { unlocked_vault } => Ok(unlocked_vault),
+        Response::Error { message } => Err(anyhow!(message)),
+        _ => bail!("unexpected agent response"),
+    }
+}
+
+/* --- server side: `sesame agent run` --- */
+
+struct AgentState {
+    // At most one unlocked vault cached at a time, matching the single
+    // `--db` a CLI invocation ever operates on.
+    cached: Option<(String, LockedKey)>,
+    last_used: Instant,
+}
+
+// Run the agent in the foreground, listening on the Unix socket until the
+// process is killed. Any cached key is cleared after `idle_ttl` of
+// inactivity. Intended to be started once (by hand, or a user service unit)
+// and left running; this function never returns on success.
+pub fn run_foreground(idle_ttl: Duration) -> Result<()> {
+    let path = socket_path()?;
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    let listener = UnixListener::bind(&path)?;
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    let state = Arc::new(Mutex::new(AgentState { cached: None, last_used: Instant::now() }));
+
+    {
+        let state = Arc::clone(&state);
+        thread::spawn(move || loop {
+            thread::sleep(Duration::from_secs(5));
+            let mut st = state.lock().unwrap_or_else(|e| e.into_inner());
+            if st.cached.is_some() && st.last_used.elapsed() >= idle_ttl {
+                st.cached = None;
+                eprintln!("agent: idle for {:?}, locked cached key.", idle_ttl);
+            }
+        });
+    }
+
+    println!("agent: listening on {}", path.display());
+    for conn in listener.incoming() {
+        let conn = conn?;
+        let state = Arc::clone(&state);
+        thread::spawn(move || {
+            if let Err(e) = handle_conn(conn, &state) {
+                eprintln!("agent: connection error: {e}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_conn(mut stream: UnixStream, state: &Arc<Mutex<AgentState>>) -> Result<()> {
+    let req: Request = read_message(&mut stream)?;
+    let mut resp = match req {
+        Request::Unlock { vault_path, mut password } => {
+            let opened = crate::db::Vault::open(&vault_path, &password);
+            password.zeroize();
+            match opened {
+                Ok(vault) => {
+                    let mut st = state.lock().unwrap_or_else(|e| e.into_inner());
+                    st.cached = Some((vault_path, vault.key.into_local()));
+                    st.last_used = Instant::now();
+                    Response::Ok
+                }
+                Err(e) => Response::Error { message: e.to_string() },
+            }
+        }
+        Request::Lock => {
+            state.lock().unwrap_or_else(|e| e.into_inner()).cached = None;
+            Response::Ok
+        }
+        Request::Encrypt { vault_path, suite_id, mut plaintext } => {
+            let mut st = state.lock().unwrap_or_else(|e| e.into_inner());
+            let resp = match &st.cached {
+                Some((cached_path, key)) if *cached_path == vault_path => {
+                    match CipherSuite::from_alg_id(suite_id).and_then(|suite| EncryptedBlob::seal_with_suite(suite, &**key, &plaintext)) {
+                        Ok(blob) => {
+                            st.last_used = Instant::now();
+                            Response::Blob { bytes: blob.encode() }
+                        }
+                        Err(e) => Response::Error { message: e.to_string() },
+                    }
+                }
+                _ => Response::Locked,
+            };
+            plaintext.zeroize();
+            resp
+        }
+        Request::Decrypt { vault_path, blob } => {
+            let mut st = state.lock().unwrap_or_else(|e| e.into_inner());
+            match &st.cached {
+                Some((cached_path, key)) if *cached_path == vault_path => {
+                    match EncryptedBlob::decode(&blob).and_then(|b| b.open(&**key)) {
+                        Ok(pt) => {
+                            st.last_used = Instant::now();
+                            Response::Plaintext { bytes: pt.to_vec() }
+                        }
+                        Err(e) => Response::Error { message: e.to_string() },
+                    }
+                }
+                _ => Response::Locked,
+            }
+        }
+        Request::WrapDek { vault_path, mut kek } => {
+            let mut st = state.lock().unwrap_or_else(|e| e.into_inner());
+            let resp = match &st.cached {
+                Some((cached_path, key)) if *cached_path == vault_path => {
+                    match crate::crypto::wrap_dek(&kek, &**key) {
+                        Ok(blob) => {
+                            st.last_used = Instant::now();
+                            Response::Blob { bytes: blob.encode() }
+                        }
+                        Err(e) => Response::Error { message: e.to_string() },
+                    }
+                }
+                _ => Response::Locked,
+            };
+            kek.zeroize();
+            resp
+        }
+        Request::Status => {
+            let st = state.lock().unwrap_or_else(|e| e.into_inner());
+            Response::Status { unlocked_vault: st.cached.as_ref().map(|(p, _)| p.clone()) }
+        }
+    };
+    let sent = write_message(&mut stream, &resp);
+    zeroize_response(&mut resp);
+    sent
+}
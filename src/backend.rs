@@ -0,0 +1,607 @@
+use anyhow::{Result, anyhow, bail};
+use rusqlite::{Connection, params};
+use std::path::Path;
+
+use crate::crypto::EncryptedBlob;
+
+// The set of primitive operations a vault needs from its storage layer.
+// Key derivation and encryption happen above this trait (see `crypto`);
+// implementors only persist and retrieve already-encrypted bytes.
+pub trait VaultBackend {
+    fn get_header(&self) -> Result<Option<HeaderRecord>>;
+    fn put_header(&self, header: &HeaderRecord) -> Result<()>;
+
+    fn get_catalog(&self) -> Result<Option<EncryptedRecord>>;
+    fn put_catalog(&self, blob: &EncryptedBlob, updated_at: i64) -> Result<()>;
+
+    fn insert_item(&self, id: &str, blob: &EncryptedBlob, created_at: i64, updated_at: i64) -> Result<()>;
+    fn update_item(&self, id: &str, blob: &EncryptedBlob, updated_at: i64) -> Result<bool>;
+    fn delete_item(&self, id: &str) -> Result<bool>;
+    fn get_item(&self, id: &str) -> Result<Option<EncryptedRecord>>;
+    fn iter_items(&self) -> Result<Vec<ItemRecord>>;
+
+    // Key slots: each wraps the vault's DEK under a password-derived KEK,
+    // so several passwords can unlock one vault and rotating a password
+    // only needs to rewrite one slot (see `db::set_master_password`).
+    fn list_key_slots(&self) -> Result<Vec<KeySlotRecord>>;
+    fn put_key_slot(&self, slot: &KeySlotRecord) -> Result<()>;
+    fn delete_key_slot(&self, slot_id: i64) -> Result<bool>;
+
+    // Append-only encrypted op log, for `sync::merge` (see that module).
+    fn append_op(&self, blob: &EncryptedBlob, lamport_ts: i64, node_id: &str) -> Result<()>;
+    fn iter_ops_after(&self, lamport_ts: i64) -> Result<Vec<OpRecord>>;
+    fn count_ops(&self) -> Result<i64>;
+    fn put_checkpoint(&self, blob: &EncryptedBlob, lamport_ts: i64) -> Result<()>;
+    fn list_checkpoints(&self) -> Result<Vec<CheckpointRecord>>;
+
+    // Atomically replace the catalog blob (if given) and every listed item
+    // blob in one transaction, so `db::rekey` re-encrypting the vault under
+    // a new cipher suite can't leave some rows on the old suite and others
+    // on the new one if it's interrupted partway through.
+    fn rekey_all(&self, catalog: Option<&EncryptedBlob>, now: i64, items: &[(String, EncryptedBlob)]) -> Result<()>;
+
+    // Copy the whole vault to `to`. Implementations that have no natural
+    // file representation (e.g. `InMemoryBackend`) may just error.
+    fn backup(&self, to: &Path) -> Result<()>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HeaderRecord {
+    pub format_version: i64,
+    pub kdf_salt: Vec<u8>,
+    pub kdf_mem_kib: i64,
+    pub kdf_iters: i64,
+    pub kdf_parallelism: i64,
+    // Per-vault Lamport clock and random node id, used to order and
+    // attribute entries in the `ops` log (see `sync`).
+    pub lamport_ts: i64,
+    pub node_id: String,
+    // The `crypto::CipherSuite` every write path seals new rows under (see
+    // `db::rekey`). Existing rows may still be sealed under an older suite
+    // until they're next rewritten - `EncryptedBlob` carries its own alg id,
+    // so reads never depend on this field.
+    pub active_suite_id: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct EncryptedRecord {
+    pub blob: EncryptedBlob,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemRecord {
+    pub id: String,
+    pub blob: EncryptedBlob,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpRecord {
+    pub lamport_ts: i64,
+    pub node_id: String,
+    pub blob: EncryptedBlob,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckpointRecord {
+    pub lamport_ts: i64,
+    pub blob: EncryptedBlob,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeySlotRecord {
+    pub slot_id: i64,
+    // See `crypto::KdfAlg` - which KDF `kdf_salt`/`kdf_mem_kib`/`kdf_iters`/
+    // `kdf_parallelism` are parameters for, so a future KDF can be added
+    // without reinterpreting existing slots' params under the wrong algorithm.
+    pub kdf_alg_id: i64,
+    pub kdf_salt: Vec<u8>,
+    pub kdf_mem_kib: i64,
+    pub kdf_iters: i64,
+    pub kdf_parallelism: i64,
+    pub wrapped_dek: EncryptedBlob,
+}
+
+/* --- SqliteBackend: the default, on-disk implementation --- */
+
+const SCHEMA_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS header(
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    format_version INTEGER NOT NULL,
+    kdf_salt BLOB NOT NULL,
+    kdf_mem_kib INTEGER NOT NULL,
+    kdf_iters INTEGER NOT NULL,
+    kdf_parallelism INTEGER NOT NULL,
+    lamport_ts INTEGER NOT NULL,
+    node_id TEXT NOT NULL,
+    active_suite_id INTEGER NOT NULL DEFAULT 1
+);
+
+CREATE TABLE IF NOT EXISTS catalog(
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    blob BLOB NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS items(
+    id TEXT PRIMARY KEY,
+    blob BLOB NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS key_slots(
+    slot_id INTEGER PRIMARY KEY,
+    kdf_alg_id INTEGER NOT NULL,
+    kdf_salt BLOB NOT NULL,
+    kdf_mem_kib INTEGER NOT NULL,
+    kdf_iters INTEGER NOT NULL,
+    kdf_parallelism INTEGER NOT NULL,
+    wrapped_dek BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS ops(
+    op_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    blob BLOB NOT NULL,
+    lamport_ts INTEGER NOT NULL,
+    node_id TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS checkpoints(
+    checkpoint_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    blob BLOB NOT NULL,
+    lamport_ts INTEGER NOT NULL
+);
+"#;
+
+pub struct SqliteBackend {
+    pub conn: Connection,
+}
+
+impl SqliteBackend {
+    // Open (or create) the sqlite file at `path` and ensure the schema exists.
+    pub fn open(path: &str) -> Result<Self> {
+        let new_file = !Path::new(path).exists();
+        let conn = Connection::open(path)?;
+
+        if new_file {
+            println!("Creating new vault at {}", path);
+        }
+
+        #[cfg(unix)]
+        restrict_vault_perms(path)?;
+
+        conn.execute_batch(SCHEMA_SQL)?;
+        Ok(SqliteBackend { conn })
+    }
+
+    // Used by tests / callers that already hold a connection (e.g. an
+    // in-memory sqlite db opened with `Connection::open_in_memory`).
+    pub fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA_SQL)?;
+        Ok(SqliteBackend { conn })
+    }
+}
+
+#[cfg(unix)]
+fn restrict_vault_perms(path: &str) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = std::fs::metadata(path)?.permissions();
+    perms.set_mode(0o600);
+    std::fs::set_permissions(path, perms)
+}
+
+impl VaultBackend for SqliteBackend {
+    fn get_header(&self) -> Result<Option<HeaderRecord>> {
+        let row = self.conn.query_row(
+            "SELECT format_version, kdf_salt, kdf_mem_kib, kdf_iters, kdf_parallelism, lamport_ts, node_id, active_suite_id FROM header WHERE id = 1",
+            [],
+            |row| Ok(HeaderRecord {
+                format_version: row.get(0)?,
+                kdf_salt: row.get(1)?,
+                kdf_mem_kib: row.get(2)?,
+                kdf_iters: row.get(3)?,
+                kdf_parallelism: row.get(4)?,
+                lamport_ts: row.get(5)?,
+                node_id: row.get(6)?,
+                active_suite_id: row.get(7)?,
+            }),
+        );
+        match row {
+            Ok(h) => Ok(Some(h)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn put_header(&self, header: &HeaderRecord) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO header (id, format_version, kdf_salt, kdf_mem_kib, kdf_iters, kdf_parallelism, lamport_ts, node_id, active_suite_id)
+             VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                format_version = excluded.format_version,
+                kdf_salt = excluded.kdf_salt,
+                kdf_mem_kib = excluded.kdf_mem_kib,
+                kdf_iters = excluded.kdf_iters,
+                kdf_parallelism = excluded.kdf_parallelism,
+                lamport_ts = excluded.lamport_ts,
+                node_id = excluded.node_id,
+                active_suite_id = excluded.active_suite_id",
+            params![header.format_version, &header.kdf_salt, header.kdf_mem_kib, header.kdf_iters, header.kdf_parallelism, header.lamport_ts, &header.node_id, header.active_suite_id],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn get_catalog(&self) -> Result<Option<EncryptedRecord>> {
+        let row = self.conn.query_row(
+            "SELECT blob, updated_at FROM catalog WHERE id = 1",
+            [],
+            |row| Ok(EncryptedRecord { blob: row.get(0)?, updated_at: row.get(1)? }),
+        );
+        match row {
+            Ok(r) => Ok(Some(r)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn put_catalog(&self, blob: &EncryptedBlob, updated_at: i64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO catalog (id, blob, updated_at) VALUES (1, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET blob = excluded.blob, updated_at = excluded.updated_at",
+            params![blob, updated_at],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn insert_item(&self, id: &str, blob: &EncryptedBlob, created_at: i64, updated_at: i64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO items (id, blob, created_at, updated_at) VALUES (?, ?, ?, ?)",
+            params![id, blob, created_at, updated_at],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn update_item(&self, id: &str, blob: &EncryptedBlob, updated_at: i64) -> Result<bool> {
+        let tx = self.conn.unchecked_transaction()?;
+        let rows = tx.execute(
+            "UPDATE items SET blob = ?, updated_at = ? WHERE id = ?",
+            params![blob, updated_at, id],
+        )?;
+        tx.commit()?;
+        Ok(rows > 0)
+    }
+
+    fn delete_item(&self, id: &str) -> Result<bool> {
+        let tx = self.conn.unchecked_transaction()?;
+        let rows = tx.execute("DELETE FROM items WHERE id = ?", [id])?;
+        tx.commit()?;
+        Ok(rows > 0)
+    }
+
+    fn get_item(&self, id: &str) -> Result<Option<EncryptedRecord>> {
+        let row = self.conn.query_row(
+            "SELECT blob, updated_at FROM items WHERE id = ?",
+            [id],
+            |row| Ok(EncryptedRecord { blob: row.get(0)?, updated_at: row.get(1)? }),
+        );
+        match row {
+            Ok(r) => Ok(Some(r)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn iter_items(&self) -> Result<Vec<ItemRecord>> {
+        let mut stmt = self.conn.prepare("SELECT id, blob, created_at, updated_at FROM items")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(ItemRecord {
+                id: row.get(0)?,
+                blob: row.get(1)?,
+                created_at: row.get(2)?,
+                updated_at: row.get(3)?,
+            })
+        })?;
+        let mut out = Vec::new();
+        for r in rows {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    fn list_key_slots(&self) -> Result<Vec<KeySlotRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT slot_id, kdf_alg_id, kdf_salt, kdf_mem_kib, kdf_iters, kdf_parallelism, wrapped_dek FROM key_slots"
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(KeySlotRecord {
+                slot_id: row.get(0)?,
+                kdf_alg_id: row.get(1)?,
+                kdf_salt: row.get(2)?,
+                kdf_mem_kib: row.get(3)?,
+                kdf_iters: row.get(4)?,
+                kdf_parallelism: row.get(5)?,
+                wrapped_dek: row.get(6)?,
+            })
+        })?;
+        let mut out = Vec::new();
+        for r in rows {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    fn put_key_slot(&self, slot: &KeySlotRecord) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO key_slots (slot_id, kdf_alg_id, kdf_salt, kdf_mem_kib, kdf_iters, kdf_parallelism, wrapped_dek)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(slot_id) DO UPDATE SET
+                kdf_alg_id = excluded.kdf_alg_id,
+                kdf_salt = excluded.kdf_salt,
+                kdf_mem_kib = excluded.kdf_mem_kib,
+                kdf_iters = excluded.kdf_iters,
+                kdf_parallelism = excluded.kdf_parallelism,
+                wrapped_dek = excluded.wrapped_dek",
+            params![slot.slot_id, slot.kdf_alg_id, &slot.kdf_salt, slot.kdf_mem_kib, slot.kdf_iters, slot.kdf_parallelism, &slot.wrapped_dek],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn delete_key_slot(&self, slot_id: i64) -> Result<bool> {
+        let tx = self.conn.unchecked_transaction()?;
+        let rows = tx.execute("DELETE FROM key_slots WHERE slot_id = ?", params![slot_id])?;
+        tx.commit()?;
+        Ok(rows > 0)
+    }
+
+    fn append_op(&self, blob: &EncryptedBlob, lamport_ts: i64, node_id: &str) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO ops (blob, lamport_ts, node_id) VALUES (?, ?, ?)",
+            params![blob, lamport_ts, node_id],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn iter_ops_after(&self, lamport_ts: i64) -> Result<Vec<OpRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT lamport_ts, node_id, blob FROM ops WHERE lamport_ts > ? ORDER BY lamport_ts, node_id"
+        )?;
+        let rows = stmt.query_map(params![lamport_ts], |row| {
+            Ok(OpRecord {
+                lamport_ts: row.get(0)?,
+                node_id: row.get(1)?,
+                blob: row.get(2)?,
+            })
+        })?;
+        let mut out = Vec::new();
+        for r in rows {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    fn count_ops(&self) -> Result<i64> {
+        self.conn.query_row("SELECT COUNT(*) FROM ops", [], |row| row.get(0)).map_err(Into::into)
+    }
+
+    fn put_checkpoint(&self, blob: &EncryptedBlob, lamport_ts: i64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO checkpoints (blob, lamport_ts) VALUES (?, ?)",
+            params![blob, lamport_ts],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn list_checkpoints(&self) -> Result<Vec<CheckpointRecord>> {
+        let mut stmt = self.conn.prepare("SELECT lamport_ts, blob FROM checkpoints ORDER BY lamport_ts")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(CheckpointRecord { lamport_ts: row.get(0)?, blob: row.get(1)? })
+        })?;
+        let mut out = Vec::new();
+        for r in rows {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    fn rekey_all(&self, catalog: Option<&EncryptedBlob>, now: i64, items: &[(String, EncryptedBlob)]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        if let Some(blob) = catalog {
+            tx.execute(
+                "INSERT INTO catalog (id, blob, updated_at) VALUES (1, ?, ?)
+                 ON CONFLICT(id) DO UPDATE SET blob = excluded.blob, updated_at = excluded.updated_at",
+                params![blob, now],
+            )?;
+        }
+        for (id, blob) in items {
+            tx.execute("UPDATE items SET blob = ?, updated_at = ? WHERE id = ?", params![blob, now, id])?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn backup(&self, to: &Path) -> Result<()> {
+        let src = self.conn.path().ok_or_else(|| anyhow!("source DB has no path"))?;
+        if to == Path::new(src) {
+            bail!("Destination and source are the same. Refusing to overwrite live database.");
+        }
+
+        if let Some(parent) = to.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut dest_conn = Connection::open(to)?;
+        {
+            use rusqlite::backup::Backup;
+            let backup = Backup::new(&self.conn, &mut dest_conn)?;
+            backup.step(-1)?;
+        }
+
+        #[cfg(unix)]
+        if let Some(to_str) = to.to_str() {
+            restrict_vault_perms(to_str)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* --- InMemoryBackend: keeps everything in process memory, for tests --- */
+
+#[derive(Default)]
+pub struct InMemoryBackend {
+    inner: std::sync::Mutex<InMemoryState>,
+}
+
+#[derive(Default)]
+struct InMemoryState {
+    header: Option<HeaderRecord>,
+    catalog: Option<EncryptedRecord>,
+    items: std::collections::BTreeMap<String, ItemRecord>,
+    key_slots: std::collections::BTreeMap<i64, KeySlotRecord>,
+    ops: Vec<OpRecord>,
+    checkpoints: Vec<CheckpointRecord>,
+}
+
+impl InMemoryBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl VaultBackend for InMemoryBackend {
+    fn get_header(&self) -> Result<Option<HeaderRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.header.clone())
+    }
+
+    fn put_header(&self, header: &HeaderRecord) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        state.header = Some(header.clone());
+        Ok(())
+    }
+
+    fn get_catalog(&self) -> Result<Option<EncryptedRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.catalog.clone())
+    }
+
+    fn put_catalog(&self, blob: &EncryptedBlob, updated_at: i64) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        state.catalog = Some(EncryptedRecord { blob: blob.clone(), updated_at });
+        Ok(())
+    }
+
+    fn insert_item(&self, id: &str, blob: &EncryptedBlob, created_at: i64, updated_at: i64) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        state.items.insert(id.to_string(), ItemRecord {
+            id: id.to_string(), blob: blob.clone(), created_at, updated_at,
+        });
+        Ok(())
+    }
+
+    fn update_item(&self, id: &str, blob: &EncryptedBlob, updated_at: i64) -> Result<bool> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        if let Some(rec) = state.items.get_mut(id) {
+            rec.blob = blob.clone();
+            rec.updated_at = updated_at;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn delete_item(&self, id: &str) -> Result<bool> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.items.remove(id).is_some())
+    }
+
+    fn get_item(&self, id: &str) -> Result<Option<EncryptedRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.items.get(id).map(|r| EncryptedRecord {
+            blob: r.blob.clone(), updated_at: r.updated_at,
+        }))
+    }
+
+    fn iter_items(&self) -> Result<Vec<ItemRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.items.values().cloned().collect())
+    }
+
+    fn list_key_slots(&self) -> Result<Vec<KeySlotRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.key_slots.values().cloned().collect())
+    }
+
+    fn put_key_slot(&self, slot: &KeySlotRecord) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        state.key_slots.insert(slot.slot_id, slot.clone());
+        Ok(())
+    }
+
+    fn delete_key_slot(&self, slot_id: i64) -> Result<bool> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.key_slots.remove(&slot_id).is_some())
+    }
+
+    fn append_op(&self, blob: &EncryptedBlob, lamport_ts: i64, node_id: &str) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        state.ops.push(OpRecord { lamport_ts, node_id: node_id.to_string(), blob: blob.clone() });
+        Ok(())
+    }
+
+    fn iter_ops_after(&self, lamport_ts: i64) -> Result<Vec<OpRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        let mut ops: Vec<OpRecord> = state.ops.iter().filter(|o| o.lamport_ts > lamport_ts).cloned().collect();
+        ops.sort_by(|a, b| a.lamport_ts.cmp(&b.lamport_ts).then(a.node_id.cmp(&b.node_id)));
+        Ok(ops)
+    }
+
+    fn count_ops(&self) -> Result<i64> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.ops.len() as i64)
+    }
+
+    fn put_checkpoint(&self, blob: &EncryptedBlob, lamport_ts: i64) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        state.checkpoints.push(CheckpointRecord { lamport_ts, blob: blob.clone() });
+        Ok(())
+    }
+
+    fn list_checkpoints(&self) -> Result<Vec<CheckpointRecord>> {
+        let state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        Ok(state.checkpoints.clone())
+    }
+
+    fn rekey_all(&self, catalog: Option<&EncryptedBlob>, now: i64, items: &[(String, EncryptedBlob)]) -> Result<()> {
+        let mut state = self.inner.lock().map_err(|_| anyhow!("in-memory backend lock poisoned"))?;
+        if let Some(blob) = catalog {
+            state.catalog = Some(EncryptedRecord { blob: blob.clone(), updated_at: now });
+        }
+        for (id, blob) in items {
+            if let Some(rec) = state.items.get_mut(id) {
+                rec.blob = blob.clone();
+                rec.updated_at = now;
+            }
+        }
+        Ok(())
+    }
+
+    fn backup(&self, _to: &Path) -> Result<()> {
+        Err(anyhow!("InMemoryBackend has no on-disk representation to back up"))
+    }
+}
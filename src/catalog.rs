@@ -1,10 +1,10 @@
 use anyhow::{Result, anyhow};
-use rusqlite::{Connection, params};
 use zeroize::Zeroize;
 
 use crate::util;
-use crate::crypto;
-use crate::db::Vault;
+use crate::backend::VaultBackend;
+use crate::crypto::CipherSuite;
+use crate::db::{Vault, VaultKey};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Zeroize)]
 #[zeroize(drop)]
@@ -14,55 +14,35 @@ pub struct CatalogEntry {
     pub updated_at: i64,
 }
 
-// Used by Vault::open, so needs conn and key args
-pub fn ensure_empty_catalog(conn: &Connection, key: &[u8; 32]) -> Result<()> {
-    let count: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM catalog WHERE id = 1",
-        [],
-        |row| row.get(0)
-    )?;
-
-    if count == 0 {
-        let plaintext = b"[]"; // empty JSON list
-        let (ciphertext, nonce) = crypto::encrypt_blob(key, plaintext)?;
-        let now = util::now_unix();
-        let tx = conn.unchecked_transaction()?;
-        tx.execute(
-            "INSERT INTO catalog (id, nonce, ciphertext, updated_at) VALUES (1, ?, ?, ?)",
-            params![&nonce[..], &ciphertext, now],
-        )?;
-        tx.commit()?;
-        println!("Created encrypted empty catalog.");
+// Used by Vault::open (and open_cached_or_prompt), so takes the backend and
+// key directly rather than a constructed `Vault`.
+pub fn ensure_empty_catalog<B: VaultBackend>(backend: &B, key: &VaultKey, suite: CipherSuite) -> Result<()> {
+    if backend.get_catalog()?.is_some() {
+        return Ok(());
     }
 
+    let plaintext = b"[]"; // empty JSON list
+    let blob = key.seal_with_suite(suite, plaintext)?;
+    let now = util::now_unix();
+    backend.put_catalog(&blob, now)?;
+    println!("Created encrypted empty catalog.");
+
     Ok(())
 }
 
-pub fn load_catalog(v: &Vault) -> Result<Vec<CatalogEntry>> {
-    // read row
-    let (nonce, ct): (Vec<u8>, Vec<u8>) = v.conn.query_row(
-        "SELECT nonce, ciphertext FROM catalog WHERE id = 1",
-        [],
-        |row| Ok((row.get(0)?, row.get(1)?)),
-    )?;
-
-    // Guard and convert nonce Vec<u8> -> [u8; 12]
-    if nonce.len() != 12 {
-        return Err(anyhow!("catalog nonce has wrong length: {}", nonce.len()));
-    }
-    let mut n = [0u8; 12];
-    n.copy_from_slice(&nonce);
+pub fn load_catalog<B: VaultBackend>(v: &Vault<B>) -> Result<Vec<CatalogEntry>> {
+    let rec = v.backend.get_catalog()?.ok_or_else(|| anyhow!("vault has no catalog"))?;
 
-    let pt = crypto::decrypt_blob(&*v.key, &n, &ct)?;
+    let pt = v.key.open(&rec.blob)?;
     if pt.is_empty() {
         return Ok(Vec::new());
     }
-    let v: Vec<CatalogEntry> = serde_json::from_slice(&pt)
+    let entries: Vec<CatalogEntry> = serde_json::from_slice(&pt)
         .map_err(|e| anyhow!("catalog json decode failed: {e:?}"))?;
-    Ok(v)
+    Ok(entries)
 }
 
-fn load_catalog_sorted(v: &Vault) -> Result<Vec<CatalogEntry>> {
+pub(crate) fn load_catalog_sorted<B: VaultBackend>(v: &Vault<B>) -> Result<Vec<CatalogEntry>> {
     let mut v_sort = load_catalog(v)?;
 
     // Order: title asc, then id asc
@@ -72,21 +52,16 @@ fn load_catalog_sorted(v: &Vault) -> Result<Vec<CatalogEntry>> {
     Ok(v_sort)
 }
 
-pub fn save_catalog(v: &Vault, entries: &[CatalogEntry]) -> Result<()> {
+pub fn save_catalog<B: VaultBackend>(v: &Vault<B>, entries: &[CatalogEntry]) -> Result<()> {
     let pt = serde_json::to_vec(entries)?;
-    let (ct, nonce) = crypto::encrypt_blob(&*v.key, &pt)?;
+    let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
     let now = util::now_unix();
-    let tx = v.conn.unchecked_transaction()?;
-    tx.execute(
-        "UPDATE catalog SET nonce = ?, ciphertext = ?, updated_at = ? WHERE id = 1",
-        params![&nonce[..], &ct, now],
-    )?;
-    tx.commit()?;
+    v.backend.put_catalog(&blob, now)?;
 
     Ok(())
 }
 
-pub fn list_items(v: &Vault) -> Result<()> {
+pub fn list_items<B: VaultBackend>(v: &Vault<B>) -> Result<()> {
     let entries = load_catalog_sorted(v)?;
     if entries.is_empty() {
         println!("(catalog is empty)");
@@ -99,7 +74,7 @@ pub fn list_items(v: &Vault) -> Result<()> {
     Ok(())
 }
 
-pub fn resolve_selector_to_id(v: &Vault, sel: &str) -> Result<String> {
+pub fn resolve_selector_to_id<B: VaultBackend>(v: &Vault<B>, sel: &str) -> Result<String> {
     let entries = load_catalog_sorted(v)?;
     if entries.is_empty() {
         return Err(anyhow!("catalog is empty"));
@@ -128,11 +103,11 @@ pub fn resolve_selector_to_id(v: &Vault, sel: &str) -> Result<String> {
     if matches.peek().is_some() {
         return Err(anyhow!("prefix {} too ambiguous", sel));
     }
-    
+
     Ok(first.unwrap().id.clone())
 }
 
-pub fn search(v: &Vault, query: &String, limit: usize, deep: bool) -> Result<()> {
+pub fn search<B: VaultBackend>(v: &Vault<B>, query: &String, limit: usize, deep: bool) -> Result<()> {
     let needle = query.to_lowercase();
     let mut entries = load_catalog(v)?;
     entries.sort_by_key(|e| std::cmp::Reverse(e.updated_at));
@@ -1,42 +1,251 @@
-use anyhow::{Result, anyhow};
-use rusqlite::{Connection};
-use chacha20poly1305::{aead::{Aead, KeyInit}, ChaCha20Poly1305};
+use anyhow::{Result, anyhow, bail};
+use chacha20poly1305::{aead::{Aead, KeyInit, Payload}, ChaCha20Poly1305, XChaCha20Poly1305};
+use aes_gcm::Aes256Gcm;
 use argon2::{Argon2, Params};
+use rusqlite::types::{ToSql, ToSqlOutput, FromSql, FromSqlError, FromSqlResult, ValueRef};
+use zeroize::Zeroize;
 
-use crate::db;
+use crate::locked::{LockedKey, LockedVec};
 
-pub fn derive_key_from_header(conn: &Connection, password: &str) -> Result<[u8; 32]> {
-    let (salt, mem_kib, iters, parallelism) = db::load_kdf_params(&conn)?;
-    let params = Params::new(mem_kib as u32, iters as u32, parallelism as u32, Some(32))
-        .map_err(|e| anyhow!("bad Argon2 params: {e:?}"))?;
-    derive_key(password, &salt, &params)
+// The AEAD cipher used to seal an `EncryptedBlob`. Stored as a one-byte id
+// (see `alg_id`) inside the blob itself, so different rows in the same
+// vault can use different suites - `db::rekey` re-seals every row under a
+// newly chosen suite without anything else needing to change. Nonce length
+// is a property of the suite, never assumed to be 12 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipherSuite {
+    ChaCha20Poly1305,
+    XChaCha20Poly1305,
+    Aes256Gcm,
 }
 
-pub fn derive_key(password: &str, salt: &[u8], params: &Params) -> Result<[u8; 32]> {
-    // Argon2id
-    let argon = Argon2::new_with_secret(&[], argon2::Algorithm::Argon2id, argon2::Version::V0x13, params.clone())
-        .map_err(|e| anyhow!("argon2 setup failed: {e:?}"))?;
-    let mut out = [0u8; 32];
-    argon.hash_password_into(password.as_bytes(), salt, &mut out)
-        .map_err(|e| anyhow!("argon2 derive failed: {e:?}"))?;
-    Ok(out)
+impl CipherSuite {
+    // pub(crate) so `agent.rs` can put the suite id on the wire without the
+    // client ever needing to construct a whole `EncryptedBlob` just to ask
+    // for one.
+    pub(crate) fn alg_id(self) -> u8 {
+        match self {
+            CipherSuite::ChaCha20Poly1305 => 1,
+            CipherSuite::XChaCha20Poly1305 => 2,
+            CipherSuite::Aes256Gcm => 3,
+        }
+    }
+
+    pub(crate) fn from_alg_id(id: u8) -> Result<Self> {
+        match id {
+            1 => Ok(CipherSuite::ChaCha20Poly1305),
+            2 => Ok(CipherSuite::XChaCha20Poly1305),
+            3 => Ok(CipherSuite::Aes256Gcm),
+            other => bail!("unsupported encrypted blob algorithm id {other}"),
+        }
+    }
+
+    fn nonce_len(self) -> usize {
+        match self {
+            CipherSuite::ChaCha20Poly1305 => 12,
+            CipherSuite::XChaCha20Poly1305 => 24,
+            CipherSuite::Aes256Gcm => 12,
+        }
+    }
+}
+
+pub const DEFAULT_CIPHER_SUITE: CipherSuite = CipherSuite::ChaCha20Poly1305;
+
+// The KDF used to turn a password into a KEK. Persisted per key slot (see
+// `backend::KeySlotRecord::kdf_alg_id`) so a future KDF can be introduced
+// without breaking slots already derived under Argon2id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfAlg {
+    Argon2id,
 }
 
-pub fn encrypt_blob(key: &[u8; 32], plaintext: &[u8]) -> Result<(Vec<u8>, [u8; 12])> {
-    let cipher = ChaCha20Poly1305::new(key.into());
-    let mut nonce = [0u8; 12];
+impl KdfAlg {
+    pub fn alg_id(self) -> i64 {
+        match self {
+            KdfAlg::Argon2id => 1,
+        }
+    }
+
+    pub fn from_alg_id(id: i64) -> Result<Self> {
+        match id {
+            1 => Ok(KdfAlg::Argon2id),
+            other => bail!("unsupported KDF algorithm id {other}"),
+        }
+    }
+}
+
+pub fn derive_key(alg: KdfAlg, password: &str, salt: &[u8], params: &Params) -> Result<LockedKey> {
+    match alg {
+        KdfAlg::Argon2id => {
+            let argon = Argon2::new_with_secret(&[], argon2::Algorithm::Argon2id, argon2::Version::V0x13, params.clone())
+                .map_err(|e| anyhow!("argon2 setup failed: {e:?}"))?;
+            let mut out = [0u8; 32];
+            argon.hash_password_into(password.as_bytes(), salt, &mut out)
+                .map_err(|e| anyhow!("argon2 derive failed: {e:?}"))?;
+            let key = LockedKey::new(out);
+            out.zeroize();
+            Ok(key)
+        }
+    }
+}
+
+// `aad` binds the suite id into the authentication tag, so swapping the
+// alg_id byte on a stored blob (e.g. to trick a reader into using a weaker
+// cipher) fails authentication instead of silently downgrading.
+fn encrypt_blob(suite: CipherSuite, key: &[u8; 32], plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut nonce = vec![0u8; suite.nonce_len()];
     getrandom::getrandom(&mut nonce)
         .map_err(|e| anyhow!("getrandom failed: {:?}", e))?;
-    let ct = cipher.encrypt(chacha20poly1305::Nonce::from_slice(&nonce), plaintext)
-        .map_err(|e| anyhow!("encrypt failed: {e:?}"))?;
+    let aad = [suite.alg_id()];
+    let payload = Payload { msg: plaintext, aad: &aad };
+
+    let ct = match suite {
+        CipherSuite::ChaCha20Poly1305 => {
+            let cipher = ChaCha20Poly1305::new(key.into());
+            cipher.encrypt(chacha20poly1305::Nonce::from_slice(&nonce), payload)
+                .map_err(|e| anyhow!("encrypt failed: {e:?}"))?
+        }
+        CipherSuite::XChaCha20Poly1305 => {
+            let cipher = XChaCha20Poly1305::new(key.into());
+            cipher.encrypt(chacha20poly1305::XNonce::from_slice(&nonce), payload)
+                .map_err(|e| anyhow!("encrypt failed: {e:?}"))?
+        }
+        CipherSuite::Aes256Gcm => {
+            let cipher = Aes256Gcm::new(key.into());
+            cipher.encrypt(aes_gcm::Nonce::from_slice(&nonce), payload)
+                .map_err(|e| anyhow!("encrypt failed: {e:?}"))?
+        }
+    };
 
     Ok((ct, nonce))
 }
 
-pub fn decrypt_blob(key: &[u8; 32], nonce: &[u8; 12], ciphertext: &[u8]) -> Result<Vec<u8>> {
-    let cipher = ChaCha20Poly1305::new(key.into());
-    let pt = cipher
-        .decrypt(chacha20poly1305::Nonce::from_slice(nonce), ciphertext)
-        .map_err(|e| anyhow!("decrypt failed: {e:?}"))?;
+fn decrypt_blob(suite: CipherSuite, key: &[u8; 32], nonce: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+    let aad = [suite.alg_id()];
+    let payload = Payload { msg: ciphertext, aad: &aad };
+
+    let pt = match suite {
+        CipherSuite::ChaCha20Poly1305 => {
+            let cipher = ChaCha20Poly1305::new(key.into());
+            cipher.decrypt(chacha20poly1305::Nonce::from_slice(nonce), payload)
+        }
+        CipherSuite::XChaCha20Poly1305 => {
+            let cipher = XChaCha20Poly1305::new(key.into());
+            cipher.decrypt(chacha20poly1305::XNonce::from_slice(nonce), payload)
+        }
+        CipherSuite::Aes256Gcm => {
+            let cipher = Aes256Gcm::new(key.into());
+            cipher.decrypt(aes_gcm::Nonce::from_slice(nonce), payload)
+        }
+    }.map_err(|e| anyhow!("decrypt failed: {e:?}"))?;
+
     Ok(pt)
 }
+
+const BLOB_FORMAT_VERSION: u8 = 1;
+
+// A self-describing, single-column encrypted blob:
+//   [1B format version][1B alg id][1B nonce_len][nonce][8B LE ct_len][ciphertext]
+// replacing the separate `nonce`/`ciphertext` columns and their scattered
+// `if nonce.len() != 12` checks with one decode path. Unknown format/alg
+// ids are rejected rather than silently misread, so a future cipher can be
+// introduced without breaking old rows (see `ToSql`/`FromSql` below).
+#[derive(Debug, Clone)]
+pub struct EncryptedBlob {
+    alg_id: u8,
+    nonce: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedBlob {
+    pub fn seal(key: &[u8; 32], plaintext: &[u8]) -> Result<Self> {
+        Self::seal_with_suite(DEFAULT_CIPHER_SUITE, key, plaintext)
+    }
+
+    // Seal under an explicitly chosen suite, used by `db::rekey` to migrate
+    // existing rows to a different cipher.
+    pub fn seal_with_suite(suite: CipherSuite, key: &[u8; 32], plaintext: &[u8]) -> Result<Self> {
+        let (ciphertext, nonce) = encrypt_blob(suite, key, plaintext)?;
+        Ok(EncryptedBlob { alg_id: suite.alg_id(), nonce, ciphertext })
+    }
+
+    pub fn open(&self, key: &[u8; 32]) -> Result<LockedVec> {
+        let suite = CipherSuite::from_alg_id(self.alg_id)?;
+        if self.nonce.len() != suite.nonce_len() {
+            bail!("encrypted blob has invalid nonce length {} for its algorithm", self.nonce.len());
+        }
+        let pt = decrypt_blob(suite, key, &self.nonce, &self.ciphertext)?;
+        Ok(LockedVec::from_vec(pt))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(3 + self.nonce.len() + 8 + self.ciphertext.len());
+        out.push(BLOB_FORMAT_VERSION);
+        out.push(self.alg_id);
+        out.push(self.nonce.len() as u8);
+        out.extend_from_slice(&self.nonce);
+        out.extend_from_slice(&(self.ciphertext.len() as u64).to_le_bytes());
+        out.extend_from_slice(&self.ciphertext);
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 3 {
+            bail!("encrypted blob too short");
+        }
+        let format_version = bytes[0];
+        if format_version != BLOB_FORMAT_VERSION {
+            bail!("unsupported encrypted blob format version {format_version}");
+        }
+        let alg_id = bytes[1];
+        let nonce_len = bytes[2] as usize;
+
+        let mut pos = 3;
+        if bytes.len() < pos + nonce_len + 8 {
+            bail!("encrypted blob truncated (nonce)");
+        }
+        let nonce = bytes[pos..pos + nonce_len].to_vec();
+        pos += nonce_len;
+
+        let ct_len = u64::from_le_bytes(bytes[pos..pos + 8].try_into().unwrap()) as usize;
+        pos += 8;
+        if bytes.len() != pos + ct_len {
+            bail!("encrypted blob length mismatch");
+        }
+        let ciphertext = bytes[pos..pos + ct_len].to_vec();
+
+        Ok(EncryptedBlob { alg_id, nonce, ciphertext })
+    }
+}
+
+impl ToSql for EncryptedBlob {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.encode()))
+    }
+}
+
+impl FromSql for EncryptedBlob {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let bytes = value.as_blob()?;
+        EncryptedBlob::decode(bytes).map_err(|e| FromSqlError::Other(e.into()))
+    }
+}
+
+// Seal a Data Encryption Key under a password-derived Key Encryption Key.
+// Just an AEAD over the 32 raw DEK bytes, so this is a thin wrapper over
+// `EncryptedBlob` with names that read right at call sites.
+pub fn wrap_dek(kek: &[u8; 32], dek: &[u8; 32]) -> Result<EncryptedBlob> {
+    EncryptedBlob::seal(kek, dek)
+}
+
+pub fn unwrap_dek(kek: &[u8; 32], wrapped: &EncryptedBlob) -> Result<LockedKey> {
+    let pt = wrapped.open(kek)?;
+    if pt.len() != 32 {
+        return Err(anyhow!("unwrapped DEK has unexpected length: {}", pt.len()));
+    }
+    let mut dek = [0u8; 32];
+    dek.copy_from_slice(&pt);
+    let locked = LockedKey::new(dek);
+    dek.zeroize();
+    Ok(locked)
+}
@@ -1,253 +1,460 @@
 use anyhow::{Result, anyhow, bail};
-use rusqlite::{Connection, params};
-use zeroize::Zeroizing;
+use zeroize::Zeroize;
 use std::path::Path;
 use argon2::Params;
 
-// Schema for the vault
-const SCHEMA_SQL: &str = r#"
-CREATE TABLE IF NOT EXISTS header(
-    id INTEGER PRIMARY KEY CHECK (id = 1),
-    format_version INTEGER NOT NULL,
-    kdf_salt BLOB NOT NULL,
-    kdf_mem_kib INTEGER NOT NULL,
-    kdf_iters INTEGER NOT NULL,
-    kdf_parallelism INTEGER NOT NULL
-);
-
-CREATE TABLE IF NOT EXISTS catalog(
-    id INTEGER PRIMARY KEY CHECK (id = 1),
-    nonce BLOB NOT NULL,
-    ciphertext BLOB NOT NULL,
-    updated_at INTEGER NOT NULL
-);
-
-CREATE TABLE IF NOT EXISTS items(
-    id TEXT PRIMARY KEY,
-    nonce BLOB NOT NULL,
-    ciphertext BLOB NOT NULL,
-    created_at INTEGER NOT NULL,
-    updated_at INTEGER NOT NULL
-);
-"#;
-
-pub struct Vault {
-    pub conn: rusqlite::Connection,
-    pub key: Zeroizing<[u8; 32]>,
-}
-
-impl Vault {
-    // Open/create DB, ensure schema/header, derive key, ensure catalog exists
-    pub fn open(db_path: &str, password: &str) -> Result<Self> {
-        let new_file = !Path::new(db_path).exists();
-        let conn = Connection::open(db_path)?;
+use crate::backend::{VaultBackend, SqliteBackend, InMemoryBackend, HeaderRecord, KeySlotRecord};
+use crate::crypto::{CipherSuite, DEFAULT_CIPHER_SUITE, EncryptedBlob, KdfAlg};
+use crate::locked::LockedKey;
+
+// format_version >= 2 means the vault uses envelope encryption: items and
+// the catalog are encrypted under a random DEK, and the DEK is wrapped
+// under one KEK per key_slots row. Versions below this are the legacy
+// single-key format, where the password-derived key encrypted items
+// directly; those are migrated to envelope encryption on open.
+const FORMAT_VERSION: i64 = 2;
+
+// Argon2id cost parameters for deriving a KEK from a master password. Tests
+// (including `Vault::open_in_memory`) use minimal-but-valid params instead -
+// nothing about the in-memory backend needs production-strength KDF cost,
+// and paying it on every test made the suite take tens of seconds per test.
+#[cfg(not(test))]
+const DEFAULT_MEM_KIB: i64 = 256 * 1024; // 256 MiB
+#[cfg(test)]
+const DEFAULT_MEM_KIB: i64 = 8; // Argon2's minimum (8 KiB per degree of parallelism)
+#[cfg(not(test))]
+const DEFAULT_ITERS: i64 = 3;
+#[cfg(test)]
+const DEFAULT_ITERS: i64 = 1; // Argon2's minimum
+const DEFAULT_PARALLELISM: i64 = 1;
+
+pub struct Vault<B: VaultBackend = SqliteBackend> {
+    pub backend: B,
+    pub key: VaultKey,
+    // The cipher suite new writes are sealed under (see `rekey`). Read paths
+    // never consult this - `EncryptedBlob` carries its own alg id - so a
+    // rekey that runs in another process is picked up the next time this
+    // one re-opens the vault.
+    pub active_suite: CipherSuite,
+}
+
+// The vault's DEK, either held directly in this process or left entirely
+// with a `sesame agent run` process that derived it. Every encrypt/decrypt
+// goes through here so call sites (`catalog`, `items`, `sync`,
+// `importexport`) don't need to care which: an agent-mediated vault sends
+// just the plaintext/blob/KEK each operation needs and gets back only the
+// matching result - the raw DEK itself is never sent back to this process.
+pub enum VaultKey {
+    Local(LockedKey),
+    Agent(String),
+}
 
-        if new_file {
-            println!("Creating new vault at {}", db_path);
+impl VaultKey {
+    pub fn seal_with_suite(&self, suite: CipherSuite, plaintext: &[u8]) -> Result<EncryptedBlob> {
+        match self {
+            VaultKey::Local(key) => EncryptedBlob::seal_with_suite(suite, &**key, plaintext),
+            VaultKey::Agent(db_path) => crate::agent::encrypt(db_path, suite, plaintext),
         }
+    }
 
-        // Restrict file permissions for mac and linux
-        #[cfg(unix)]
-        restrict_vault_perms(db_path)?;
+    pub fn open(&self, blob: &EncryptedBlob) -> Result<crate::locked::LockedVec> {
+        match self {
+            VaultKey::Local(key) => blob.open(&**key),
+            VaultKey::Agent(db_path) => crate::agent::decrypt(db_path, blob),
+        }
+    }
 
-        // Schema + header
-        conn.execute_batch(SCHEMA_SQL)?;
-        ensure_header(&conn)?;
+    // Wrap a DEK we already hold under a freshly derived KEK, for adding or
+    // rotating a key slot. For an agent-mediated key, the wrapping happens
+    // inside the agent process too - only the resulting wrapped blob comes
+    // back over the socket.
+    fn wrap(&self, kek: &[u8; 32]) -> Result<EncryptedBlob> {
+        match self {
+            VaultKey::Local(key) => crate::crypto::wrap_dek(kek, &**key),
+            VaultKey::Agent(db_path) => crate::agent::wrap_dek(db_path, kek),
+        }
+    }
+
+    // Only `Vault::open`/`open_with_backend` ever produce a `Local` key, so
+    // the agent (which always unlocks with a real password, never a cached
+    // one) can pull the raw key back out to hold in its own cache.
+    pub(crate) fn into_local(self) -> LockedKey {
+        match self {
+            VaultKey::Local(key) => key,
+            VaultKey::Agent(_) => unreachable!("Vault::open never returns an agent-mediated key"),
+        }
+    }
+}
 
-        // Derive key from header params
-        let key_bytes: [u8; 32] = crate::crypto::derive_key_from_header(&conn, password)?;
-        let key = Zeroizing::new(key_bytes);
+impl Vault<SqliteBackend> {
+    // Open/create the on-disk sqlite vault, ensure header/catalog, derive key.
+    pub fn open(db_path: &str, password: &str) -> Result<Self> {
+        let backend = SqliteBackend::open(db_path)?;
+        Vault::open_with_backend(backend, password)
+    }
 
-        // Ensure catalog row exists (idempotent)
-        crate::catalog::ensure_empty_catalog(&conn, &*key)?;
+    // Open the vault, preferring a key already cached by `sesame agent run`
+    // over re-deriving it from a freshly prompted password. Falls back to
+    // the normal prompt-and-derive path whenever no agent is reachable, or
+    // it has nothing cached for this vault; on a successful prompt-path
+    // open, best-effort pushes the freshly derived key to the agent so the
+    // next command can skip the prompt too.
+    pub fn open_cached_or_prompt(db_path: &str) -> Result<Self> {
+        let backend = SqliteBackend::open(db_path)?;
+
+        if crate::agent::has_cached_key(db_path) {
+            let active_suite = backend.get_header()?
+                .map(|h| CipherSuite::from_alg_id(h.active_suite_id as u8))
+                .transpose()?
+                .unwrap_or(crate::crypto::DEFAULT_CIPHER_SUITE);
+            let key = VaultKey::Agent(db_path.to_string());
+            crate::catalog::ensure_empty_catalog(&backend, &key, active_suite)?;
+            return Ok(Vault { backend, key, active_suite });
+        }
 
-        Ok(Vault { conn, key })
+        let password = crate::util::prompt_password()?;
+        let vault = Vault::open_with_backend(backend, password.as_str())?;
+        crate::agent::cache_unlocked(db_path, password.as_str());
+        Ok(vault)
     }
 }
 
-#[cfg(unix)]
-fn restrict_vault_perms(path: &str) -> std::io::Result<()> {
-    use std::os::unix::fs::PermissionsExt;
-    let mut perms = std::fs::metadata(path)?.permissions();
-    perms.set_mode(0o600);
-    std::fs::set_permissions(path, perms)
+impl Vault<InMemoryBackend> {
+    // Convenience constructor for tests: a fresh in-memory vault.
+    pub fn open_in_memory(password: &str) -> Result<Self> {
+        Vault::open_with_backend(InMemoryBackend::new(), password)
+    }
 }
 
-fn ensure_header(conn: &Connection) -> Result<()> {
-    // Do we already have the header?
-    let count: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM header WHERE id = 1",
-        [],
-        |row| row.get(0),
-    )?;
-
-    if count == 0 {
-        // --- KDF parameters ---
-        // mem_kib: 256 MiB, iters: 3, parallelism: 1
-        let format_version: i64 = 1;
-        let kdf_mem_kib: i64 = 256 * 1024; // 256 MiB in KiB
-        let kdf_iters: i64 = 3;
-        let kdf_parallelism: i64 = 1;
-
-        // 16-byte random salt
-        let mut salt = [0u8; 16];
-        getrandom::getrandom(&mut salt)
-            .map_err(|e| anyhow!("salt generation failed (getrandom): {:?}", e))?;
+impl<B: VaultBackend> Vault<B> {
+    // Shared open path for any backend: ensure header, unlock (or create)
+    // the DEK, migrating a legacy single-key vault if one is found.
+    pub fn open_with_backend(backend: B, password: &str) -> Result<Self> {
+        let header = ensure_header(&backend)?;
+
+        let key = if header.format_version < FORMAT_VERSION {
+            migrate_legacy_vault(&backend, &header, password)?
+        } else if backend.list_key_slots()?.is_empty() {
+            create_first_key_slot(&backend, password)?
+        } else {
+            unlock_dek(&backend, password)?
+        };
+
+        let active_suite = CipherSuite::from_alg_id(header.active_suite_id as u8)?;
+        let key = VaultKey::Local(key);
+        crate::catalog::ensure_empty_catalog(&backend, &key, active_suite)?;
+
+        Ok(Vault { backend, key, active_suite })
+    }
+}
 
-        let tx = conn.unchecked_transaction()?;
-        tx.execute(
-            "INSERT INTO header (id, format_version, kdf_salt, kdf_mem_kib, kdf_iters, kdf_parallelism) VALUES (1, ?, ?, ?, ?, ?)",
-            params![format_version, &salt[..], kdf_mem_kib, kdf_iters, kdf_parallelism],
-        )?;
-        tx.commit()?;
+fn ensure_header<B: VaultBackend>(backend: &B) -> Result<HeaderRecord> {
+    if let Some(header) = backend.get_header()? {
+        return Ok(header);
+    }
 
-        println!("Inserted header with new random salt.");
-    } 
+    // New vaults carry no password-derived key in the header itself;
+    // per-slot kdf_salt/mem_kib/iters/parallelism in `key_slots` do that job.
+    let header = HeaderRecord {
+        format_version: FORMAT_VERSION,
+        kdf_salt: Vec::new(),
+        kdf_mem_kib: 0,
+        kdf_iters: 0,
+        kdf_parallelism: 0,
+        lamport_ts: 0,
+        node_id: crate::util::new_id()?,
+        active_suite_id: DEFAULT_CIPHER_SUITE.alg_id() as i64,
+    };
+    backend.put_header(&header)?;
+    println!("Inserted new vault header.");
+    Ok(header)
+}
 
-    Ok(())
+// Bump the per-vault Lamport clock for a new local event and persist it.
+// Returns the new timestamp to stamp on the op being appended.
+pub fn next_lamport_ts<B: VaultBackend>(backend: &B) -> Result<i64> {
+    let mut header = backend.get_header()?.ok_or_else(|| anyhow!("vault has no header"))?;
+    header.lamport_ts += 1;
+    let ts = header.lamport_ts;
+    backend.put_header(&header)?;
+    Ok(ts)
 }
 
-pub fn load_kdf_params(conn: &Connection) -> Result<(Vec<u8>, i64, i64, i64)> {
-    let (salt, mem_kib, iters, parallelism): (Vec<u8>, i64, i64, i64) = conn.query_row(
-        "SELECT kdf_salt, kdf_mem_kib, kdf_iters, kdf_parallelism FROM header WHERE id = 1",
-        [],
-        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-    )?;
-    if salt.len() < 8 {
-        return Err(anyhow!("header has invalid salt length"));
+// Bump the Lamport clock past `seen_ts`, as required when merging in
+// operations from another vault copy (see `sync::merge`).
+pub fn bump_lamport_past<B: VaultBackend>(backend: &B, seen_ts: i64) -> Result<()> {
+    let mut header = backend.get_header()?.ok_or_else(|| anyhow!("vault has no header"))?;
+    if seen_ts >= header.lamport_ts {
+        header.lamport_ts = seen_ts + 1;
+        backend.put_header(&header)?;
     }
-    Ok((salt, mem_kib, iters, parallelism))
+    Ok(())
 }
 
-pub fn set_master_password(v: &Vault, old_pw: &str, new_pw: &str) -> Result<()> {
-    // derive old key from current header
-    let old_key_bytes: [u8; 32] = crate::crypto::derive_key_from_header(&v.conn, old_pw)?;
-    let old_key = Zeroizing::new(old_key_bytes);
+pub fn node_id<B: VaultBackend>(backend: &B) -> Result<String> {
+    Ok(backend.get_header()?.ok_or_else(|| anyhow!("vault has no header"))?.node_id)
+}
 
-    // get new kdf inputs (and new salt)
-    let mem_kib: i64 = 256 * 1024; // 256 MiB in KiB
-    let iters: i64 = 3;
-    let parallelism: i64 = 1;
+fn fresh_salt() -> Result<[u8; 16]> {
     let mut salt = [0u8; 16];
     getrandom::getrandom(&mut salt)
         .map_err(|e| anyhow!("salt generation failed (getrandom): {:?}", e))?;
+    Ok(salt)
+}
 
-    // derive new key
+fn derive_kek(alg_id: i64, password: &str, salt: &[u8], mem_kib: i64, iters: i64, parallelism: i64) -> Result<LockedKey> {
+    let alg = KdfAlg::from_alg_id(alg_id)?;
     let params = Params::new(mem_kib as u32, iters as u32, parallelism as u32, Some(32))
         .map_err(|e| anyhow!("bad Argon2 params: {e:?}"))?;
-    let new_key_bytes: [u8; 32] = crate::crypto::derive_key(new_pw, &salt, &params)?;
-    let new_key = Zeroizing::new(new_key_bytes);
-
-    // begin db transaction
-    let tx = v.conn.unchecked_transaction()?;
-
-    // each item in items
-    let mut sel = tx.prepare("SELECT id, nonce, ciphertext FROM items")?;
-    let rows = sel.query_map([], |r| {
-        Ok((
-            r.get::<_, String>(0)?,     // id
-            r.get::<_, Vec<u8>>(1)?,    // nonce
-            r.get::<_, Vec<u8>>(2)?,    // ciphertext
-        ))
-    })?;
-    let mut upd = tx.prepare(
-        "UPDATE items
-            SET nonce = ?, ciphertext = ?, updated_at = ?
-        WHERE id = ?"
-    )?;
-    let now = crate::util::now_unix();
+    crate::crypto::derive_key(alg, password, salt, &params)
+}
+
+// Derive a fresh random DEK, wrap it under a password-derived KEK with new
+// KDF parameters, and store it as the vault's only key slot (slot 0).
+fn create_first_key_slot<B: VaultBackend>(backend: &B, password: &str) -> Result<LockedKey> {
+    let mut dek_bytes = [0u8; 32];
+    getrandom::getrandom(&mut dek_bytes)
+        .map_err(|e| anyhow!("getrandom failed: {:?}", e))?;
+    let dek = LockedKey::new(dek_bytes);
+    dek_bytes.zeroize();
 
-    for row in rows {
-        let (id, old_nonce_vec, old_ct) = row?;
-        // convert nonce
-        if old_nonce_vec.len() != 12 {
-            bail!("item {} had invalid nonce length {}", id, old_nonce_vec.len());
+    insert_new_slot(backend, 0, password, |kek| crate::crypto::wrap_dek(kek, &*dek))?;
+
+    Ok(dek)
+}
+
+// Try every key slot's password-derived KEK until one unwraps the DEK.
+// An AEAD auth failure on a slot just means "wrong password for that slot",
+// not a decoding error, so we keep trying the remaining slots.
+fn unlock_dek<B: VaultBackend>(backend: &B, password: &str) -> Result<LockedKey> {
+    let slots = backend.list_key_slots()?;
+    if slots.is_empty() {
+        bail!("vault has no key slots");
+    }
+
+    for slot in &slots {
+        let kek = derive_kek(slot.kdf_alg_id, password, &slot.kdf_salt, slot.kdf_mem_kib, slot.kdf_iters, slot.kdf_parallelism)?;
+        if let Ok(dek) = crate::crypto::unwrap_dek(&kek, &slot.wrapped_dek) {
+            return Ok(dek);
         }
-        let mut old_nonce = [0u8; 12];
-        old_nonce.copy_from_slice(&old_nonce_vec);
-        // decrypt with old key and old nonce
-        let pt = crate::crypto::decrypt_blob(&old_key, &old_nonce, &old_ct)?;
-        // encrypt pt with new key and new nonce
-        let (new_ct, new_nonce) = crate::crypto::encrypt_blob(&new_key, &pt)?;
-        // UPDATE row
-        upd.execute(
-            params![&new_nonce[..], &new_ct, now, id],
-        )?;
+    }
+
+    Err(anyhow!("incorrect master password"))
+}
+
+// `wrap` performs the actual DEK-under-KEK sealing; callers pass either a
+// plain closure over a `LockedKey` they already hold (vault creation,
+// migration, password change) or `VaultKey::wrap` (adding a slot to an
+// already-open vault, which may be agent-mediated).
+fn insert_new_slot<B: VaultBackend>(
+    backend: &B,
+    slot_id: i64,
+    password: &str,
+    wrap: impl FnOnce(&[u8; 32]) -> Result<EncryptedBlob>,
+) -> Result<()> {
+    let salt = fresh_salt()?;
+    let kdf_alg_id = KdfAlg::Argon2id.alg_id();
+    let kek = derive_kek(kdf_alg_id, password, &salt, DEFAULT_MEM_KIB, DEFAULT_ITERS, DEFAULT_PARALLELISM)?;
+    let wrapped_dek: EncryptedBlob = wrap(&kek)?;
+
+    backend.put_key_slot(&KeySlotRecord {
+        slot_id,
+        kdf_alg_id,
+        kdf_salt: salt.to_vec(),
+        kdf_mem_kib: DEFAULT_MEM_KIB,
+        kdf_iters: DEFAULT_ITERS,
+        kdf_parallelism: DEFAULT_PARALLELISM,
+        wrapped_dek,
+    })
+}
+
+// Upgrade a v1 (single-key) vault in place: derive the old item key directly
+// from the header's kdf params, re-encrypt every item and the catalog under
+// a freshly generated DEK, wrap that DEK in a new slot 0, and bump
+// format_version. Runs once, the first time such a vault is opened.
+fn migrate_legacy_vault<B: VaultBackend>(backend: &B, header: &HeaderRecord, password: &str) -> Result<LockedKey> {
+    println!("Migrating vault to envelope encryption...");
+
+    // Legacy (v1) vaults predate the pluggable KDF id; they were always Argon2id.
+    let old_key = derive_kek(KdfAlg::Argon2id.alg_id(), password, &header.kdf_salt, header.kdf_mem_kib, header.kdf_iters, header.kdf_parallelism)?;
 
+    let mut dek_bytes = [0u8; 32];
+    getrandom::getrandom(&mut dek_bytes)
+        .map_err(|e| anyhow!("getrandom failed: {:?}", e))?;
+    let dek = LockedKey::new(dek_bytes);
+    dek_bytes.zeroize();
+
+    let now = crate::util::now_unix();
+
+    for rec in backend.iter_items()? {
+        let pt = rec.blob.open(&old_key)?;
+        let new_blob = EncryptedBlob::seal(&dek, &pt)?;
+        backend.update_item(&rec.id, &new_blob, now)?;
+        drop(pt);
+    }
+
+    if let Some(catalog_rec) = backend.get_catalog()? {
+        let pt = catalog_rec.blob.open(&old_key)?;
+        let new_blob = EncryptedBlob::seal(&dek, &pt)?;
+        backend.put_catalog(&new_blob, now)?;
         drop(pt);
     }
-    drop(upd);
-    drop(sel);
-
-    // catalog (one row, no need to go through items after decryption)
-    let (old_nonce_vec_c, old_ct): (Vec<u8>, Vec<u8>) = tx.query_row(
-        "SELECT nonce, ciphertext FROM catalog WHERE id = 1",
-        [],
-        |r| Ok((r.get(0)?, r.get(1)?))
-    )?;
-    if old_nonce_vec_c.len() != 12 {
-        bail!("catalog had invalid nonce length {}", old_nonce_vec_c.len());
-    }
-    let mut old_nonce = [0u8; 12];
-    old_nonce.copy_from_slice(&old_nonce_vec_c);
-    // decrypt with old key and old nonce
-    let pt = crate::crypto::decrypt_blob(&old_key, &old_nonce, &old_ct)?;
-    // encrypt pt with new key and new nonce
-    let (new_ct, new_nonce) = crate::crypto::encrypt_blob(&new_key, &pt)?;
-    // UPDATE row 
-    tx.execute(
-        "UPDATE catalog
-            SET nonce = ?, ciphertext = ?, updated_at = ?
-        WHERE id = 1",
-        params![&new_nonce[..], &new_ct, now],
-    )?;
-    drop(pt);
-
-    // update header
-    // (opt) if kdf params changed, change them
-    tx.execute(
-        "UPDATE header 
-        SET kdf_salt = ?, kdf_mem_kib = ?, kdf_iters = ?, kdf_parallelism = ?
-        WHERE id = 1",
-        params![&salt[..], mem_kib, iters, parallelism],
-    )?;
-    tx.commit()?;
+
+    insert_new_slot(backend, 0, password, |kek| crate::crypto::wrap_dek(kek, &dek))?;
+
+    let node_id = if header.node_id.is_empty() { crate::util::new_id()? } else { header.node_id.clone() };
+    backend.put_header(&HeaderRecord {
+        format_version: FORMAT_VERSION,
+        kdf_salt: Vec::new(),
+        kdf_mem_kib: 0,
+        kdf_iters: 0,
+        kdf_parallelism: 0,
+        lamport_ts: header.lamport_ts,
+        node_id,
+        active_suite_id: DEFAULT_CIPHER_SUITE.alg_id() as i64,
+    })?;
+
+    println!("Migration complete.");
+    Ok(dek)
+}
+
+// O(1): find the slot that `old_pw` unlocks and rewrite only its wrapped_dek
+// under a newly derived KEK. Items and the catalog are never touched.
+pub fn set_master_password<B: VaultBackend>(v: &Vault<B>, old_pw: &str, new_pw: &str) -> Result<()> {
+    let slots = v.backend.list_key_slots()?;
+    let mut found = None;
+
+    for slot in &slots {
+        let kek = derive_kek(slot.kdf_alg_id, old_pw, &slot.kdf_salt, slot.kdf_mem_kib, slot.kdf_iters, slot.kdf_parallelism)?;
+        if let Ok(dek) = crate::crypto::unwrap_dek(&kek, &slot.wrapped_dek) {
+            found = Some((slot.slot_id, dek));
+            break;
+        }
+    }
+
+    // Re-derive the DEK from `old_pw` itself rather than trusting `v.key` -
+    // this also means changing a password works the same whether `v` was
+    // opened locally or via the agent.
+    let (slot_id, dek) = found.ok_or_else(|| anyhow!("incorrect current master password"))?;
+    insert_new_slot(&v.backend, slot_id, new_pw, |kek| crate::crypto::wrap_dek(kek, &dek))?;
 
     Ok(())
 }
 
-pub fn backup_to_path(v: &Vault, to: &str, overwrite: bool) -> Result<()> {
-    let dest = Path::new(&to);
-    // Check if path is the same as current DB path
-    let src = v.conn.path().ok_or_else(|| anyhow!("Source DB has no path"))?;
-    if dest == Path::new(src) {
-        bail!("Destination and source are the same. Refusing to overwrite live databse.");
+// Add a brand-new key slot wrapping the already-unlocked DEK under
+// `new_pw`, so the vault can be opened with several distinct passwords.
+pub fn add_key_slot<B: VaultBackend>(v: &Vault<B>, new_pw: &str) -> Result<i64> {
+    let next_id = v.backend.list_key_slots()?.iter().map(|s| s.slot_id).max().map_or(0, |m| m + 1);
+    insert_new_slot(&v.backend, next_id, new_pw, |kek| v.key.wrap(kek))?;
+    Ok(next_id)
+}
+
+// Remove a key slot by id. Refuses to remove the last remaining slot, since
+// that would make the vault permanently unopenable.
+pub fn remove_key_slot<B: VaultBackend>(v: &Vault<B>, slot_id: i64) -> Result<()> {
+    let slots = v.backend.list_key_slots()?;
+    if slots.len() <= 1 {
+        bail!("refusing to remove the last key slot");
     }
+    if !v.backend.delete_key_slot(slot_id)? {
+        bail!("no key slot with id {slot_id}");
+    }
+    Ok(())
+}
+
+// Re-encrypt the catalog and every item under a newly chosen cipher suite,
+// in a single backend transaction (see `VaultBackend::rekey_all`) so a
+// failure partway through can't leave some rows on the old suite and
+// others on the new one. The DEK and key slots are untouched - only which
+// AEAD cipher protects each row changes. Also persists `suite` to the
+// header as the vault's active suite, so every write path after this
+// (`catalog::save_catalog`, `items::add_item_interactive`/`edit_item`,
+// `sync::record_op`/`write_checkpoint`, `importexport::import`) keeps
+// sealing new rows under it instead of reverting to the default.
+pub fn rekey<B: VaultBackend>(v: &Vault<B>, suite: CipherSuite) -> Result<()> {
+    let now = crate::util::now_unix();
+
+    let catalog_blob = match v.backend.get_catalog()? {
+        Some(rec) => {
+            let pt = v.key.open(&rec.blob)?;
+            Some(v.key.seal_with_suite(suite, &pt)?)
+        }
+        None => None,
+    };
+
+    let mut items = Vec::new();
+    for rec in v.backend.iter_items()? {
+        let pt = v.key.open(&rec.blob)?;
+        let new_blob = v.key.seal_with_suite(suite, &pt)?;
+        items.push((rec.id, new_blob));
+    }
+
+    let item_count = items.len();
+    v.backend.rekey_all(catalog_blob.as_ref(), now, &items)?;
+
+    let mut header = v.backend.get_header()?.ok_or_else(|| anyhow!("vault has no header"))?;
+    header.active_suite_id = suite.alg_id() as i64;
+    v.backend.put_header(&header)?;
+
+    println!("Rekeyed catalog and {item_count} item(s) to the new cipher suite.");
+
+    Ok(())
+}
+
+pub fn backup_to_path<B: VaultBackend>(v: &Vault<B>, to: &str, overwrite: bool) -> Result<()> {
+    let dest = Path::new(&to);
 
     // If something's at dest, and no overwrite, fail
     if !overwrite && dest.exists() {
         bail!("Destination already exists. Use a different path or overwrite with --overwrite flag.");
     }
 
-    // Ensure dest parent dir exists
-    if let Some(parent) = dest.parent() {
-        std::fs::create_dir_all(parent)?;
+    v.backend.backup(dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_in_memory_creates_first_key_slot_and_empty_catalog() {
+        let v = Vault::open_in_memory("hunter2").unwrap();
+        assert_eq!(v.backend.list_key_slots().unwrap().len(), 1);
+        assert!(crate::catalog::load_catalog(&v).unwrap().is_empty());
+    }
+
+    #[test]
+    fn open_in_memory_rejects_wrong_password() {
+        let v = Vault::open_with_backend(InMemoryBackend::new(), "right").unwrap();
+        assert!(unlock_dek(&v.backend, "wrong").is_err());
     }
 
-    // Open dest Connection
-    let mut dest_conn = rusqlite::Connection::open(dest)?;
+    #[test]
+    fn add_and_remove_key_slot_roundtrip() {
+        let v = Vault::open_in_memory("hunter2").unwrap();
+        let slot_id = add_key_slot(&v, "second-password").unwrap();
+        assert_eq!(v.backend.list_key_slots().unwrap().len(), 2);
 
-    // Backup
-    {
-        use rusqlite::backup::Backup;
-        let backup = Backup::new(&v.conn, &mut dest_conn)?;
-        // -1 means copy all pages in one go
-        backup.step(-1)?;
+        remove_key_slot(&v, slot_id).unwrap();
+        assert_eq!(v.backend.list_key_slots().unwrap().len(), 1);
     }
 
-    // Tighten permissions on unix
-    #[cfg(unix)]
-    restrict_vault_perms(to)?;
+    #[test]
+    fn set_master_password_rotates_the_unlock_password() {
+        let v = Vault::open_in_memory("old-password").unwrap();
+        set_master_password(&v, "old-password", "new-password").unwrap();
 
-    Ok(())
+        let slot = &v.backend.list_key_slots().unwrap()[0];
+        let kek = derive_kek(slot.kdf_alg_id, "new-password", &slot.kdf_salt, slot.kdf_mem_kib, slot.kdf_iters, slot.kdf_parallelism).unwrap();
+        assert!(crate::crypto::unwrap_dek(&kek, &slot.wrapped_dek).is_ok());
+    }
+
+    #[test]
+    fn rekey_persists_the_active_suite_for_later_writes() {
+        let v = Vault::open_in_memory("hunter2").unwrap();
+        rekey(&v, CipherSuite::Aes256Gcm).unwrap();
+
+        let header = v.backend.get_header().unwrap().unwrap();
+        assert_eq!(CipherSuite::from_alg_id(header.active_suite_id as u8).unwrap(), CipherSuite::Aes256Gcm);
+    }
 }
@@ -0,0 +1,188 @@
+use anyhow::{Result, anyhow, bail};
+use zeroize::Zeroizing;
+
+use crate::backend::VaultBackend;
+use crate::catalog::{self, CatalogEntry};
+use crate::items::{self, ItemPlain};
+use crate::db::Vault;
+use crate::util;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum OnConflict {
+    Skip,
+    Merge,
+}
+
+const CSV_HEADER: &str = "title,username,password,notes";
+
+// Walk every item and write it out as plaintext CSV/JSON, or (with
+// `encrypted`) as an AEAD-sealed archive under the vault key. Always asks
+// for confirmation first, since this is the one command that can put every
+// secret in the vault into a single unencrypted file.
+pub fn export<B: VaultBackend>(v: &Vault<B>, to: &str, format: ExportFormat, encrypted: bool) -> Result<()> {
+    let warning = if encrypted {
+        format!("This writes an encrypted archive of every item to '{to}'. Continue?")
+    } else {
+        format!("This writes every password in PLAINTEXT to '{to}'. Continue?")
+    };
+    if !util::confirm(&warning) {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let entries = catalog::load_catalog(v)?;
+    let mut out_items = Vec::with_capacity(entries.len());
+    for e in &entries {
+        out_items.push(items::load_item(v, &e.id)?);
+    }
+
+    let plaintext = match format {
+        ExportFormat::Json => serde_json::to_vec_pretty(&out_items)?,
+        ExportFormat::Csv => {
+            let mut csv = String::new();
+            csv.push_str(CSV_HEADER);
+            csv.push('\n');
+            for item in &out_items {
+                csv.push_str(&csv_row(item));
+                csv.push('\n');
+            }
+            csv.into_bytes()
+        }
+    };
+
+    if encrypted {
+        let blob = v.key.seal_with_suite(v.active_suite, &plaintext)?;
+        std::fs::write(to, blob.encode())?;
+    } else {
+        std::fs::write(to, plaintext)?;
+    }
+
+    println!("Exported {} item(s) to {}", out_items.len(), to);
+    Ok(())
+}
+
+// Parse a CSV/JSON export (plaintext only; encrypted archives aren't a
+// supported import source) and create items through the normal
+// encrypt-and-insert path, skipping or merging on a title collision.
+pub fn import<B: VaultBackend>(v: &Vault<B>, from: &str, format: ExportFormat, on_conflict: OnConflict) -> Result<()> {
+    let raw = std::fs::read_to_string(from)?;
+    let parsed: Vec<ItemPlain> = match format {
+        ExportFormat::Json => serde_json::from_str(&raw)?,
+        ExportFormat::Csv => parse_csv(&raw)?,
+    };
+
+    let mut entries = catalog::load_catalog(v)?;
+    let now = util::now_unix();
+    let mut imported = 0usize;
+    let mut skipped = 0usize;
+
+    for item in parsed {
+        let existing = entries.iter().position(|e| e.title.eq_ignore_ascii_case(&item.title));
+
+        if let Some(idx) = existing {
+            if on_conflict == OnConflict::Skip {
+                skipped += 1;
+                continue;
+            }
+            // Merge: overwrite the existing item's fields in place.
+            let id = entries[idx].id.clone();
+            let pt = Zeroizing::new(serde_json::to_vec(&item)?);
+            let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
+            v.backend.update_item(&id, &blob, now)?;
+            entries[idx].updated_at = now;
+            imported += 1;
+            continue;
+        }
+
+        let id = util::new_id()?;
+        let pt = Zeroizing::new(serde_json::to_vec(&item)?);
+        let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
+        v.backend.insert_item(&id, &blob, now, now)?;
+        entries.push(CatalogEntry { id, title: item.title.clone(), updated_at: now });
+        imported += 1;
+    }
+
+    catalog::save_catalog(v, &entries)?;
+    println!("Imported {imported} item(s), skipped {skipped} duplicate(s).");
+    Ok(())
+}
+
+fn csv_row(item: &ItemPlain) -> String {
+    [&item.title, &item.username, &item.password, &item.notes]
+        .iter()
+        .map(|f| csv_escape(f))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn parse_csv(raw: &str) -> Result<Vec<ItemPlain>> {
+    let mut lines = raw.lines();
+    let header = lines.next().ok_or_else(|| anyhow!("empty CSV file"))?;
+    if header.trim() != CSV_HEADER {
+        bail!("unexpected CSV header, expected '{CSV_HEADER}'");
+    }
+
+    let mut items = Vec::new();
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(line);
+        if fields.len() != 4 {
+            bail!("expected 4 columns, got {}: {line}", fields.len());
+        }
+        items.push(ItemPlain {
+            title: fields[0].clone(),
+            username: fields[1].clone(),
+            password: fields[2].clone(),
+            notes: fields[3].clone(),
+        });
+    }
+    Ok(items)
+}
+
+// Minimal RFC4180-ish splitter: handles quoted fields with embedded commas
+// and doubled-quote escaping, but not quoted newlines (one record per line).
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut cur = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    cur.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                cur.push(c);
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == ',' {
+            fields.push(std::mem::take(&mut cur));
+        } else {
+            cur.push(c);
+        }
+    }
+    fields.push(cur);
+    fields
+}
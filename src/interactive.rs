@@ -0,0 +1,186 @@
+// Interactive raw-mode catalog picker: puts the terminal into raw mode and
+// reads input key-by-key (rather than whole lines via `util::read_line`),
+// showing a live-filtered view of the catalog that narrows as the user
+// types, with up/down arrows to move the selection and Enter to act on the
+// highlighted item. Only the chosen item is ever decrypted - the filtered
+// view works entirely off `CatalogEntry` titles/ids.
+use anyhow::{Result, bail};
+use std::io::{self, Read, Write};
+use std::os::unix::io::AsRawFd;
+
+use crate::backend::VaultBackend;
+use crate::catalog::{self, CatalogEntry};
+use crate::db::Vault;
+use crate::util;
+
+const MAX_ROWS: usize = 10;
+
+// Puts stdin into raw mode (no line buffering, no echo, Ctrl-C read as a
+// key rather than raised as SIGINT) on construction and
+// restores the original terminal settings in `Drop`, so a panic mid-picker
+// can't leave the user's shell in raw mode - the same guarantee `LockedVec`/
+// `LockedKey` give for secret memory, applied to terminal state instead.
+struct RawModeGuard {
+    fd: i32,
+    original: libc::termios,
+}
+
+impl RawModeGuard {
+    fn enable() -> Result<Self> {
+        let fd = io::stdin().as_raw_fd();
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            bail!("tcgetattr failed: {}", io::Error::last_os_error());
+        }
+
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ECHO | libc::ICANON | libc::ISIG);
+        raw.c_cc[libc::VMIN] = 1;
+        raw.c_cc[libc::VTIME] = 0;
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            bail!("tcsetattr failed: {}", io::Error::last_os_error());
+        }
+
+        Ok(RawModeGuard { fd, original })
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        unsafe { libc::tcsetattr(self.fd, libc::TCSANOW, &self.original) };
+    }
+}
+
+enum Key {
+    Char(char),
+    Backspace,
+    Enter,
+    Up,
+    Down,
+    CtrlC,
+    Other,
+}
+
+// Decode one key from raw stdin: printable bytes, Backspace (0x7f or 0x08),
+// Ctrl-C (0x03), Enter (CR/LF), and CSI arrow sequences (`ESC [ A` / `ESC [
+// B`). Anything else is reported as `Key::Other` and ignored by the picker.
+fn read_key<R: Read>(r: &mut R) -> Result<Key> {
+    let mut b = [0u8; 1];
+    r.read_exact(&mut b)?;
+
+    Ok(match b[0] {
+        0x03 => Key::CtrlC,
+        0x7f | 0x08 => Key::Backspace,
+        b'\r' | b'\n' => Key::Enter,
+        0x1b => {
+            let mut seq = [0u8; 2];
+            if r.read_exact(&mut seq).is_err() || seq[0] != b'[' {
+                return Ok(Key::Other);
+            }
+            match seq[1] {
+                b'A' => Key::Up,
+                b'B' => Key::Down,
+                _ => Key::Other,
+            }
+        }
+        c if c.is_ascii_graphic() || c == b' ' => Key::Char(c as char),
+        _ => Key::Other,
+    })
+}
+
+// Same substring matching `catalog::search` uses on titles, plus a prefix
+// match on the id so a partial id also narrows the list.
+fn matches(entry: &CatalogEntry, needle: &str) -> bool {
+    needle.is_empty()
+        || entry.title.to_lowercase().contains(needle)
+        || entry.id.starts_with(needle)
+}
+
+// Redraw the picker in place: move the cursor back up over the previous
+// frame, clear it, then print the search line and up to `MAX_ROWS` matches
+// with the selection marked. Returns the number of lines just printed, so
+// the next call knows how far to move back up.
+fn render(query: &str, filtered: &[&CatalogEntry], selected: usize, prev_lines: usize) -> Result<usize> {
+    let mut out = io::stdout();
+    if prev_lines > 0 {
+        write!(out, "\x1b[{prev_lines}A")?;
+    }
+    write!(out, "\r\x1b[0J")?;
+    write!(out, "Search: {query}\r\n")?;
+
+    let mut lines = 1;
+    if filtered.is_empty() {
+        write!(out, "(no matches)\r\n")?;
+        lines += 1;
+    } else {
+        for (i, e) in filtered.iter().take(MAX_ROWS).enumerate() {
+            let marker = if i == selected { ">" } else { " " };
+            let id_prefix = &e.id[..e.id.len().min(12)];
+            write!(out, "{marker} {id_prefix:<12}  {}\r\n", e.title)?;
+            lines += 1;
+        }
+    }
+
+    out.flush()?;
+    Ok(lines)
+}
+
+// Run the picker over `entries` and return the id of the chosen item, or
+// `None` if the user aborted with Ctrl-C.
+fn run_picker(entries: &[CatalogEntry]) -> Result<Option<String>> {
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+
+    let mut query = String::new();
+    let mut selected = 0usize;
+    let mut prev_lines = 0usize;
+
+    loop {
+        let filtered: Vec<&CatalogEntry> = entries.iter()
+            .filter(|e| matches(e, &query.to_lowercase()))
+            .collect();
+        if selected >= filtered.len() {
+            selected = filtered.len().saturating_sub(1);
+        }
+
+        prev_lines = render(&query, &filtered, selected, prev_lines)?;
+
+        match read_key(&mut input)? {
+            Key::CtrlC => return Ok(None),
+            Key::Enter => return Ok(filtered.get(selected).map(|e| e.id.clone())),
+            Key::Char(c) => { query.push(c); selected = 0; }
+            Key::Backspace => { query.pop(); }
+            Key::Up => if selected > 0 { selected -= 1 },
+            Key::Down => if selected + 1 < filtered.len() { selected += 1 },
+            Key::Other => {}
+        }
+    }
+}
+
+// Interactive fuzzy-finder over the catalog: live-filtered by title/id as
+// the user types, navigated with arrow keys, Enter copies the highlighted
+// item's password to the clipboard. Decryption stays lazy - only the chosen
+// item is ever loaded.
+pub fn browse_catalog<B: VaultBackend>(v: &Vault<B>) -> Result<()> {
+    let entries = catalog::load_catalog_sorted(v)?;
+    if entries.is_empty() {
+        println!("(catalog is empty)");
+        return Ok(());
+    }
+
+    let chosen_id = {
+        let _raw = RawModeGuard::enable()?;
+        run_picker(&entries)?
+    };
+
+    match chosen_id {
+        Some(id) => {
+            let item = crate::items::load_item(v, &id)?;
+            let secret = zeroize::Zeroizing::new(item.password.clone());
+            util::clipboard_copy_pw_temporary(secret, 20)?;
+        }
+        None => println!("Aborted."),
+    }
+
+    Ok(())
+}
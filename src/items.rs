@@ -1,12 +1,12 @@
 use anyhow::{Result, anyhow};
-use rusqlite::{params};
 use std::io::{Write};
 use zeroize::Zeroize;
 
 use crate::util;
-use crate::crypto;
 use crate::catalog;
+use crate::backend::VaultBackend;
 use crate::db::Vault;
+use crate::sync::{self, OpKind};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Zeroize)]
 #[zeroize(drop)]
@@ -17,23 +17,12 @@ pub struct ItemPlain {
     pub notes: String,
 }
 
-pub fn load_item(v: &Vault, id: &str) -> Result<ItemPlain> {
+pub fn load_item<B: VaultBackend>(v: &Vault<B>, id: &str) -> Result<ItemPlain> {
     // Fetch encrypted row by ID
-    let (nonce, ct): (Vec<u8>, Vec<u8>) = v.conn.query_row(
-        "SELECT nonce, ciphertext FROM items WHERE id = ?",
-        [id],
-        |row| Ok((row.get(0)?, row.get(1)?)),
-    ).map_err(|_| anyhow!("No item found with ID {id}"))?;
-
-    // Check and convert nonce Vec<u8> -> [u8; 12]
-    if nonce.len() != 12 {
-        return Err(anyhow!("catalog nonce has wrong length: {}", nonce.len()));
-    }
-    let mut n = [0u8; 12];
-    n.copy_from_slice(&nonce);
+    let rec = v.backend.get_item(id)?.ok_or_else(|| anyhow!("No item found with ID {id}"))?;
 
     // Decrypt into plaintext JSON
-    let pt = crypto::decrypt_blob(&*v.key, &n, &ct)?;
+    let pt = v.key.open(&rec.blob)?;
 
     // Parse into struct and print
     let item: ItemPlain = serde_json::from_slice(&pt)
@@ -42,7 +31,7 @@ pub fn load_item(v: &Vault, id: &str) -> Result<ItemPlain> {
     Ok(item)
 }
 
-pub fn show_item(v: &Vault, id: &str) -> Result<()> {
+pub fn show_item<B: VaultBackend>(v: &Vault<B>, id: &str) -> Result<()> {
     // Fetch encrypted row by ID
     let item = load_item(v, id)?;
 
@@ -57,7 +46,7 @@ pub fn show_item(v: &Vault, id: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn add_item_interactive(v: &Vault) -> Result<()> {
+pub fn add_item_interactive<B: VaultBackend>(v: &Vault<B>) -> Result<()> {
     // Collect fields (mask the secret input)
     let title = util::read_line("Title: ")?;
     let username = util::read_line("Username: ")?;
@@ -76,15 +65,11 @@ pub fn add_item_interactive(v: &Vault) -> Result<()> {
     let pt = zeroize::Zeroizing::new(serde_json::to_vec(&item)?);
 
     // Encrypt + insert into items
-    let (ct, nonce) = crypto::encrypt_blob(&*v.key, &pt)?;
+    let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
     let id = util::new_id()?;
     let now = util::now_unix();
-    let tx = v.conn.unchecked_transaction()?;
-    tx.execute(
-        "INSERT INTO items (id, nonce, ciphertext, created_at, updated_at) VALUES (?, ?, ?, ?, ?)",
-        params![&id, &nonce[..], &ct, now, now],
-    )?;
-    tx.commit()?;
+    v.backend.insert_item(&id, &blob, now, now)?;
+    sync::record_op(v, OpKind::Add, &id, serde_json::to_value(&item)?)?;
 
     // Update catalog
     let mut entries = catalog::load_catalog(v)?;
@@ -102,7 +87,7 @@ pub fn add_item_interactive(v: &Vault) -> Result<()> {
     Ok(())
 }
 
-pub fn edit_item(v: &Vault, id: &str) -> Result<()> {
+pub fn edit_item<B: VaultBackend>(v: &Vault<B>, id: &str) -> Result<()> {
     // 1) Load current
     let mut item = load_item(v, id)?;
 
@@ -112,6 +97,14 @@ pub fn edit_item(v: &Vault, id: &str) -> Result<()> {
     let pw_opt = util::prompt_password_optional("*hidden*")?;
     let new_notes = util::prompt_with_default("Notes", &item.notes)?;
 
+    // Only changed fields go into the op log, so a concurrent edit of a
+    // different field on another copy survives `sync::merge`.
+    let mut changed = serde_json::Map::new();
+    if new_title != item.title { changed.insert("title".into(), new_title.clone().into()); }
+    if new_username != item.username { changed.insert("username".into(), new_username.clone().into()); }
+    if let Some(new_pw) = &pw_opt { changed.insert("password".into(), new_pw.clone().into()); }
+    if new_notes != item.notes { changed.insert("notes".into(), new_notes.clone().into()); }
+
     if let Some(new_pw) = pw_opt {
         item.password = new_pw;
     }
@@ -121,17 +114,13 @@ pub fn edit_item(v: &Vault, id: &str) -> Result<()> {
 
     // 3) Re-encrypt and update row
     let pt = zeroize::Zeroizing::new(serde_json::to_vec(&item)?);
-    let (ct, nonce) = crypto::encrypt_blob(&*v.key, &pt)?;
+    let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
     let now = util::now_unix();
-    let tx = v.conn.unchecked_transaction()?;
-    let rows = tx.execute(
-        "UPDATE items SET nonce = ?, ciphertext = ?, updated_at = ? WHERE id = ?",
-        params![&nonce[..], &ct, now, id],
-    )?;
-    if rows == 0 {
+    let updated = v.backend.update_item(id, &blob, now)?;
+    if !updated {
         return Err(anyhow!("Item disappeared during edit (id {id})"));
     }
-    tx.commit()?;
+    sync::record_op(v, OpKind::Edit, id, serde_json::Value::Object(changed))?;
 
     // 4) Update catalog title + updated_at, then re-encrypt/save
     let mut entries = catalog::load_catalog(v)?;
@@ -152,15 +141,14 @@ pub fn edit_item(v: &Vault, id: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn delete_item(v: &Vault, id: &str) -> Result<()> {
+pub fn delete_item<B: VaultBackend>(v: &Vault<B>, id: &str) -> Result<()> {
     // Delete the encrypted row
-    let tx = v.conn.unchecked_transaction()?;
-    let rows = tx.execute("DELETE FROM items WHERE id = ?", [id])?;
-    if rows == 0 {
+    let deleted = v.backend.delete_item(id)?;
+    if !deleted {
         println!("No item found with ID {id}");
         return Ok(());
     }
-    tx.commit()?;
+    sync::record_op(v, OpKind::Delete, id, serde_json::Value::Null)?;
 
     // Update catalog: remove entry and re-encrypt
     let mut entries = catalog::load_catalog(v)?;
@@ -170,7 +158,7 @@ pub fn delete_item(v: &Vault, id: &str) -> Result<()> {
         // Catalog didn't have entry
         println!("Deleted item, but it wasn't in the catalog list.");
     }
-    
+
     catalog::save_catalog(v, &entries)?;
     println!("Deleted {id}");
     Ok(())
@@ -0,0 +1,123 @@
+// Anti-swap secret memory: pages backing these buffers are locked with
+// `mlock`/`VirtualLock` (via the `region` crate) so the kernel can never
+// write derived keys or decrypted plaintext out to a swap or hibernation
+// file, and the bytes are zeroized as soon as the buffer is dropped -
+// including on panic, since `Drop` always runs during unwinding.
+//
+// If the process can't lock memory (most commonly `RLIMIT_MEMLOCK` is too
+// low for an unprivileged process), we fall back to a plain zeroized buffer
+// rather than aborting; `warn_lock_failed` makes sure the user is told about the
+// degraded guarantee exactly once per process.
+use std::ops::{Deref, DerefMut};
+use std::sync::Once;
+use zeroize::Zeroize;
+
+static MLOCK_WARNING: Once = Once::new();
+
+fn warn_lock_failed(err: &region::Error) {
+    MLOCK_WARNING.call_once(|| {
+        eprintln!(
+            "warning: could not lock secret memory ({err}); \
+             falling back to unlocked (still zeroized) memory for this process"
+        );
+    });
+}
+
+// A variable-length locked buffer, used for decrypted plaintext (catalog
+// JSON, item JSON, op payloads) whose size isn't known until decrypt time.
+//
+// `_guard` is declared before `bytes` so that field-drop order (declaration
+// order, after `Drop::drop` runs) unlocks the region before the backing
+// allocation is freed - zeroize, then unlock, then deallocate.
+pub struct LockedVec {
+    _guard: Option<region::LockGuard>,
+    bytes: Box<[u8]>,
+}
+
+impl LockedVec {
+    pub fn zeroed(len: usize) -> Self {
+        // Pre-size with the final capacity up front: growing a Vec later
+        // would copy the (unlocked) old allocation's bytes into a fresh one.
+        let bytes = vec![0u8; len].into_boxed_slice();
+        let _guard = lock_region(&bytes);
+        LockedVec { bytes, _guard }
+    }
+
+    pub fn from_vec(mut data: Vec<u8>) -> Self {
+        let mut buf = Self::zeroed(data.len());
+        buf.bytes.copy_from_slice(&data);
+        data.zeroize();
+        buf
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl Deref for LockedVec {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl DerefMut for LockedVec {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl Drop for LockedVec {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+// A fixed-size locked buffer for 32-byte keys (the DEK, KEKs, and
+// `Vault::key`). Derefs to `[u8; 32]` so existing call sites that take
+// `&[u8; 32]` (e.g. `crypto::wrap_dek`) need no changes.
+//
+// Field order matters here too: see `LockedVec` above.
+pub struct LockedKey {
+    _guard: Option<region::LockGuard>,
+    bytes: Box<[u8; 32]>,
+}
+
+impl LockedKey {
+    pub fn new(key: [u8; 32]) -> Self {
+        let bytes = Box::new(key);
+        let _guard = lock_region(bytes.as_slice());
+        LockedKey { bytes, _guard }
+    }
+}
+
+impl Deref for LockedKey {
+    type Target = [u8; 32];
+    fn deref(&self) -> &[u8; 32] {
+        &self.bytes
+    }
+}
+
+impl Drop for LockedKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+// Locks `bytes` in place and returns the `LockGuard` that keeps it locked -
+// dropping the guard (not calling a separate unlock function) is what
+// releases the lock, so the caller must hold onto it for as long as the
+// memory needs to stay resident.
+fn lock_region(bytes: &[u8]) -> Option<region::LockGuard> {
+    if bytes.is_empty() {
+        return None;
+    }
+    match unsafe { region::lock(bytes.as_ptr(), bytes.len()) } {
+        Ok(guard) => Some(guard),
+        Err(e) => {
+            warn_lock_failed(&e);
+            None
+        }
+    }
+}
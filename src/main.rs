@@ -4,10 +4,19 @@ use zeroize::Zeroizing;
 use indoc::indoc;
 
 mod util;
+mod wordlist;
+mod locked;
 mod crypto;
+mod backend;
 mod db;
 mod catalog;
 mod items;
+mod sync;
+mod importexport;
+mod agent;
+mod interactive;
+
+use importexport::{ExportFormat, OnConflict};
 
 /* --- CLI types --- */
 
@@ -34,7 +43,8 @@ const SESAME_ASCII: &str = indoc! {r#"
     after_long_help = indoc! {"
         EXAMPLES:
           sesame --db vault.db init 
-          sesame add 
+          sesame add
+          sesame browse
           sesame show <ID_OR_PREFIX> --copy --timeout 40
           sesame gen --len 24 --no-specials
           sesame backup ./vault.backup.sqlite --overwrite
@@ -82,6 +92,15 @@ enum Cmd {
     "})]
     List,
 
+    // Interactive incremental-search picker over the catalog
+    #[command(visible_alias = "find", long_about = indoc! {"
+        Open a live-filtered picker over the catalog: type to narrow by
+        title or ID, use up/down arrows to move the selection, and press
+        Enter to copy the highlighted item's password to the clipboard.
+        Ctrl-C aborts without copying anything.
+    "})]
+    Browse,
+
     // Show a single item by full ID
     #[command(visible_alias = "cat", long_about = indoc! {"
         Reveal an item by index or ID prefix (must be at least 4 digits of ID).
@@ -128,8 +147,15 @@ enum Cmd {
         are enabled, with a default length of 16.
         Use the `--no-*` toggles to exclude categories.
         Use the `--len` flag to change the length.
+
+        Pass `--words N` (or `--entropy BITS` to derive the word count from
+        a target entropy) to instead generate a memorable multi-word
+        passphrase from the built-in wordlist. The bundled list has 7776
+        generated four-letter words, the same size as the EFF diceware
+        list (~12.9 bits/word); --entropy derives the word count from the
+        list's actual size.
     "})]
-    Gen { 
+    Gen {
         #[arg(long, value_name = "N", default_value_t = 16)]
         len: usize,
 
@@ -148,6 +174,25 @@ enum Cmd {
         #[arg(long)] no_lower: bool,
         #[arg(long)] no_digits: bool,
         #[arg(long)] no_specials: bool,
+
+        // Passphrase mode: N words instead of a character string
+        #[arg(long, value_name = "N", conflicts_with = "entropy",
+            long_help = "Generate a passphrase of N words from the built-in wordlist instead of a character password."
+        )]
+        words: Option<usize>,
+
+        #[arg(long, value_name = "BITS",
+            long_help = "Generate a passphrase sized to reach at least this many bits of entropy, instead of a fixed word count."
+        )]
+        entropy: Option<u32>,
+
+        #[arg(long, default_value = "-", value_name = "SEP",
+            long_help = "Separator joining words in --words/--entropy mode."
+        )]
+        sep: String,
+
+        #[arg(long, help = "Capitalize every word, instead of just one random word, in --words/--entropy mode.")]
+        capitalize: bool,
     },
 
     // Change master password
@@ -161,7 +206,7 @@ enum Cmd {
         Case-insensitive substring match over titles, usernames, and notes.
         Combine with `show` / `edit` / `delete` using the returned IDs.
     "})]
-    Search { 
+    Search {
         // Text to search for
         #[arg(value_name = "QUERY")]
         query: String,
@@ -169,6 +214,10 @@ enum Cmd {
         // Max results (0 is unlimited)
         #[arg(value_name = "N", long, default_value_t = 0)]
         limit: usize,
+
+        // Also match usernames/notes (requires decrypting every item without a title hit)
+        #[arg(long, help = "Also search usernames and notes, not just titles (slower: decrypts every non-matching item).")]
+        deep: bool,
     },
 
     // Create a backup of the vault
@@ -177,7 +226,7 @@ enum Cmd {
         By default, this fails if the destination already exists.
         Use the `--overwrite` flag to allow overwriting of destination file.
     "})]
-    Backup { 
+    Backup {
         // Destination filepath
         #[arg(value_name = "FILE")]
         to: String,
@@ -186,6 +235,156 @@ enum Cmd {
         #[arg(long)]
         overwrite: bool,
     },
+
+    // Manage additional key slots (extra master/recovery passwords)
+    #[command(long_about = indoc! {"
+        Add or remove key slots. Each slot wraps the vault's encryption key
+        under its own password, so several passwords (e.g. a recovery
+        password) can open the same vault.
+    "})]
+    Key {
+        #[command(subcommand)]
+        action: KeyAction,
+    },
+
+    // Re-encrypt the catalog and all items under a different cipher suite
+    #[command(long_about = indoc! {"
+        Decrypt the catalog and every item under the vault's current key,
+        then re-seal them all under the chosen AEAD cipher suite, in a
+        single transaction. The master password and key slots are
+        unaffected; only which cipher protects each row changes.
+    "})]
+    Rekey {
+        #[arg(value_enum, value_name = "SUITE")]
+        suite: CipherSuiteArg,
+    },
+
+    // Merge in the ops from another copy of this vault
+    #[command(long_about = indoc! {"
+        Open another copy of this vault (same master password) and merge its
+        changes into this one: newest-common-checkpoint ops from both sides
+        are replayed with last-writer-wins per field. Safe to run repeatedly
+        across machines to keep copies converged without a server.
+    "})]
+    Merge {
+        #[arg(value_name = "OTHER_DB")]
+        other: String,
+    },
+
+    // Export items to a portable CSV/JSON file (or an encrypted archive)
+    #[command(long_about = indoc! {"
+        Decrypt every item and write it to FILE as CSV or JSON.
+        Asks for confirmation first, since plaintext output contains every
+        password in the vault. Use --encrypted to instead write an
+        AEAD-sealed archive under the vault key.
+    "})]
+    Export {
+        #[arg(value_name = "FILE")]
+        to: String,
+
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
+
+        #[arg(long, help = "Write an AEAD-sealed archive instead of plaintext.")]
+        encrypted: bool,
+    },
+
+    // Import items from a CSV/JSON file produced by `export` (or another manager)
+    #[command(long_about = indoc! {"
+        Parse FILE as CSV or JSON and create an item for each row/entry,
+        through the same encrypt-and-insert path as `add`.
+        Use --on-conflict to control what happens when an item with the
+        same title already exists (default: skip).
+    "})]
+    Import {
+        #[arg(value_name = "FILE")]
+        from: String,
+
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
+
+        #[arg(long, value_enum, default_value = "skip")]
+        on_conflict: OnConflict,
+    },
+
+    // Background key-caching agent: holds the derived key so other commands
+    // don't re-prompt for the master password every time
+    #[command(long_about = indoc! {"
+        Manage the background agent that caches the vault's unlocked key in
+        locked memory behind a local Unix socket. Once unlocked, other
+        commands against the same --db skip the master password prompt until
+        the agent locks again (explicitly, or after its idle timeout).
+    "})]
+    Agent {
+        #[command(subcommand)]
+        action: AgentAction,
+    },
+}
+
+#[derive(Parser)]
+enum AgentAction {
+    // Run the agent in the foreground
+    #[command(long_about = indoc! {"
+        Start the agent and block, listening on its Unix socket until killed.
+        Typically run under a service manager or in a dedicated terminal.
+    "})]
+    Run {
+        #[arg(long, default_value_t = 900, value_name = "SECS",
+            long_help = "Lock the cached key after this many seconds of inactivity."
+        )]
+        idle_timeout: u64,
+    },
+
+    // Unlock and cache the key for --db immediately
+    #[command(long_about = indoc! {"
+        Prompt for the master password and send it to the already-running
+        agent, which derives the key and caches it for --db.
+    "})]
+    Unlock,
+
+    // Drop the cached key
+    #[command(long_about = "Clear any cached key held by the agent.")]
+    Lock,
+
+    // Report whether a vault is currently cached
+    #[command(long_about = "Print whether the agent is running and which vault (if any) it has unlocked.")]
+    Status,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum CipherSuiteArg {
+    Chacha20poly1305,
+    Xchacha20poly1305,
+    Aes256gcm,
+}
+
+impl From<CipherSuiteArg> for crypto::CipherSuite {
+    fn from(arg: CipherSuiteArg) -> Self {
+        match arg {
+            CipherSuiteArg::Chacha20poly1305 => crypto::CipherSuite::ChaCha20Poly1305,
+            CipherSuiteArg::Xchacha20poly1305 => crypto::CipherSuite::XChaCha20Poly1305,
+            CipherSuiteArg::Aes256gcm => crypto::CipherSuite::Aes256Gcm,
+        }
+    }
+}
+
+#[derive(Parser)]
+enum KeyAction {
+    // Add a new key slot unlocked by a new password
+    #[command(long_about = indoc! {"
+        Prompt for the current master password to unlock the vault, then for
+        a new password to protect a freshly created key slot.
+    "})]
+    Add,
+
+    // Remove a key slot by id
+    #[command(long_about = indoc! {"
+        Delete the given key slot. Refuses to remove the last remaining slot.
+    "})]
+    Remove {
+        #[arg(value_name = "SLOT_ID")]
+        slot_id: i64,
+    },
 }
 
 /* --- main function --- */
@@ -195,24 +394,24 @@ fn main() -> Result<()> {
 
     match cli.cmd {
         Cmd::Init => {
-            let pw = util::prompt_password()?;
-            let _v = db::Vault::open(&cli.db, pw.as_str())?;
+            let _v = db::Vault::open_cached_or_prompt(&cli.db)?;
             println!("Initialized vault at '{}'", &cli.db);
         }
         Cmd::Add => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
             items::add_item_interactive(&v)?;
             catalog::list_items(&v)?;
         }
         Cmd::List => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
             catalog::list_items(&v)?;
         }
+        Cmd::Browse => {
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
+            interactive::browse_catalog(&v)?;
+        }
         Cmd::Show { sel, copy, timeout } => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
             let id = catalog::resolve_selector_to_id(&v, &sel)?;
             items::show_item(&v, &id)?;
 
@@ -223,8 +422,7 @@ fn main() -> Result<()> {
             }
         }
         Cmd::Delete { sel } => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
             let id = catalog::resolve_selector_to_id(&v, &sel)?;
 
             // confirm before deleting
@@ -239,8 +437,7 @@ fn main() -> Result<()> {
             catalog::list_items(&v)?;
         }
         Cmd::Edit { sel } => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
             let id = catalog::resolve_selector_to_id(&v, &sel)?;
 
             // confirm before editing
@@ -255,9 +452,14 @@ fn main() -> Result<()> {
             items::show_item(&v, &id)?;
         }
         Cmd::Gen { len, copy, timeout,
-            no_upper, no_lower, no_digits, no_specials
+            no_upper, no_lower, no_digits, no_specials,
+            words, entropy, sep, capitalize,
         } => {
-            let new_pw = util::gen_password(len, no_upper, no_lower, no_digits, no_specials)?;
+            let new_pw = if words.is_some() || entropy.is_some() {
+                util::gen_passphrase(words, entropy, &sep, capitalize)?
+            } else {
+                util::gen_password(len, no_upper, no_lower, no_digits, no_specials)?
+            };
             println!("Generated password.");
 
             if copy {
@@ -275,17 +477,65 @@ fn main() -> Result<()> {
             db::set_master_password(&v, old_pw.as_str(), new_pw.as_str())?;
             println!("New master password set.");
         }
-        Cmd::Search { query, limit } => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
-            catalog::search(&v, &query, limit)?;
+        Cmd::Search { query, limit, deep } => {
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
+            catalog::search(&v, &query, limit, deep)?;
         }
         Cmd::Backup { to, overwrite } => {
-            let pw = util::prompt_password()?;
-            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
             db::backup_to_path(&v, &to, overwrite)?;
             println!("Backup written to {}", to);
         }
+        Cmd::Key { action } => {
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
+            match action {
+                KeyAction::Add => {
+                    println!("Adding a new key slot.");
+                    let new_pw = util::prompt_new_password()?;
+                    let slot_id = db::add_key_slot(&v, new_pw.as_str())?;
+                    println!("Key slot {} added.", slot_id);
+                }
+                KeyAction::Remove { slot_id } => {
+                    db::remove_key_slot(&v, slot_id)?;
+                    println!("Key slot {} removed.", slot_id);
+                }
+            }
+        }
+        Cmd::Rekey { suite } => {
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
+            db::rekey(&v, suite.into())?;
+        }
+        Cmd::Merge { other } => {
+            let pw = util::prompt_password()?;
+            let v = db::Vault::open(&cli.db, pw.as_str())?;
+            sync::merge(&v, &other, pw.as_str())?;
+        }
+        Cmd::Export { to, format, encrypted } => {
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
+            importexport::export(&v, &to, format, encrypted)?;
+        }
+        Cmd::Import { from, format, on_conflict } => {
+            let v = db::Vault::open_cached_or_prompt(&cli.db)?;
+            importexport::import(&v, &from, format, on_conflict)?;
+        }
+        Cmd::Agent { action } => match action {
+            AgentAction::Run { idle_timeout } => {
+                agent::run_foreground(std::time::Duration::from_secs(idle_timeout))?;
+            }
+            AgentAction::Unlock => {
+                let pw = util::prompt_password()?;
+                agent::unlock(&cli.db, pw.as_str())?;
+                println!("Vault unlocked and cached by agent.");
+            }
+            AgentAction::Lock => {
+                agent::lock()?;
+                println!("Agent locked.");
+            }
+            AgentAction::Status => match agent::status()? {
+                Some(path) => println!("Unlocked: {path}"),
+                None => println!("Locked."),
+            },
+        },
     }
 
     Ok(())
@@ -0,0 +1,224 @@
+use anyhow::Result;
+use serde::{Serialize, Deserialize};
+use zeroize::Zeroizing;
+use std::collections::{HashMap, HashSet};
+
+use crate::backend::{VaultBackend, SqliteBackend, OpRecord};
+use crate::catalog::{self, CatalogEntry};
+use crate::items::ItemPlain;
+use crate::db::{self, Vault, VaultKey};
+
+// Write a full encrypted checkpoint (snapshot of items + catalog) every
+// this many ops, so `merge` doesn't need to replay the whole op log.
+const KEEP_STATE_EVERY: i64 = 64;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OpKind {
+    Add,
+    Edit,
+    Delete,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct OpPayload {
+    kind: OpKind,
+    item_id: String,
+    fields: serde_json::Value,
+    lamport_ts: i64,
+    node_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    catalog: Vec<CatalogEntry>,
+    items: Vec<(String, ItemPlain)>,
+}
+
+// Append one mutation to the op log under a freshly bumped Lamport
+// timestamp, then checkpoint if we've crossed `KEEP_STATE_EVERY`.
+pub fn record_op<B: VaultBackend>(v: &Vault<B>, kind: OpKind, item_id: &str, fields: serde_json::Value) -> Result<()> {
+    let lamport_ts = db::next_lamport_ts(&v.backend)?;
+    let node_id = db::node_id(&v.backend)?;
+
+    let payload = OpPayload { kind, item_id: item_id.to_string(), fields, lamport_ts, node_id: node_id.clone() };
+    let pt = Zeroizing::new(serde_json::to_vec(&payload)?);
+    let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
+    v.backend.append_op(&blob, lamport_ts, &node_id)?;
+
+    if v.backend.count_ops()? % KEEP_STATE_EVERY == 0 {
+        write_checkpoint(v, lamport_ts)?;
+    }
+
+    Ok(())
+}
+
+fn write_checkpoint<B: VaultBackend>(v: &Vault<B>, lamport_ts: i64) -> Result<()> {
+    let catalog_entries = catalog::load_catalog(v)?;
+    let mut items = Vec::with_capacity(catalog_entries.len());
+    for entry in &catalog_entries {
+        items.push((entry.id.clone(), crate::items::load_item(v, &entry.id)?));
+    }
+    let snapshot = Snapshot { catalog: catalog_entries, items };
+    let pt = Zeroizing::new(serde_json::to_vec(&snapshot)?);
+    let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
+    v.backend.put_checkpoint(&blob, lamport_ts)?;
+    Ok(())
+}
+
+fn decrypt_op(key: &VaultKey, rec: &OpRecord) -> Result<OpPayload> {
+    let pt = key.open(&rec.blob)?;
+    Ok(serde_json::from_slice(&pt)?)
+}
+
+// Merge another copy of this vault (opened with the same master password)
+// into `v`: find the newest checkpoint timestamp both sides share, seed
+// replay state from our own newest checkpoint at or before that point (so
+// we don't have to decrypt every live item from scratch), then collect
+// every op since from each side and replay on top with last-writer-wins
+// per field. Deletes are tombstones so a delete can't be resurrected by an
+// older edit, and concurrent edits to different fields of one item both
+// survive. Remote ops we didn't already have are appended to our own log.
+pub fn merge(v: &Vault<SqliteBackend>, other_path: &str, password: &str) -> Result<()> {
+    let other = Vault::<SqliteBackend>::open(other_path, password)?;
+
+    let local_checkpoint_ts: HashSet<i64> = v.backend.list_checkpoints()?.iter().map(|c| c.lamport_ts).collect();
+    let common_ts = other.backend.list_checkpoints()?.iter()
+        .map(|c| c.lamport_ts)
+        .filter(|ts| local_checkpoint_ts.contains(ts))
+        .max()
+        .unwrap_or(0);
+
+    // Seed replay state from the newest local checkpoint at or before
+    // `common_ts`, if we have one - so replay only has to walk ops since
+    // that snapshot instead of decrypting every live item from scratch.
+    // Without a usable checkpoint (e.g. a young vault with under
+    // `KEEP_STATE_EVERY` ops), fall back to seeding from the current items.
+    let seed_checkpoint = v.backend.list_checkpoints()?.into_iter()
+        .filter(|c| c.lamport_ts <= common_ts)
+        .max_by_key(|c| c.lamport_ts);
+
+    let (seed_ts, mut items): (i64, HashMap<String, ItemPlain>) = match seed_checkpoint {
+        Some(cp) => {
+            let pt = v.key.open(&cp.blob)?;
+            let snapshot: Snapshot = serde_json::from_slice(&pt)?;
+            (cp.lamport_ts, snapshot.items.into_iter().collect())
+        }
+        None => {
+            let mut items = HashMap::new();
+            for entry in catalog::load_catalog(v)? {
+                items.insert(entry.id.clone(), crate::items::load_item(v, &entry.id)?);
+            }
+            (0, items)
+        }
+    };
+
+    let mut merged: Vec<OpPayload> = Vec::new();
+    let mut local_op_keys: HashSet<(i64, String)> = HashSet::new();
+    for rec in v.backend.iter_ops_after(seed_ts)? {
+        local_op_keys.insert((rec.lamport_ts, rec.node_id.clone()));
+        merged.push(decrypt_op(&v.key, &rec)?);
+    }
+
+    let mut new_remote_ops: Vec<OpPayload> = Vec::new();
+    for rec in other.backend.iter_ops_after(common_ts)? {
+        if local_op_keys.contains(&(rec.lamport_ts, rec.node_id.clone())) {
+            continue; // already in our log, e.g. from an earlier merge
+        }
+        let payload = decrypt_op(&other.key, &rec)?;
+        new_remote_ops.push(payload.clone());
+        merged.push(payload);
+    }
+
+    merged.sort_by(|a, b| a.lamport_ts.cmp(&b.lamport_ts).then(a.node_id.cmp(&b.node_id)));
+
+    // Apply the merged ops on top of the seed with last-writer-wins tracked
+    // per (item_id, field).
+    let mut field_ts: HashMap<(String, String), i64> = HashMap::new();
+    let mut tombstones: HashMap<String, i64> = HashMap::new();
+
+    for op in &merged {
+        match op.kind {
+            OpKind::Delete => {
+                let ts = tombstones.entry(op.item_id.clone()).or_insert(0);
+                if op.lamport_ts > *ts {
+                    *ts = op.lamport_ts;
+                }
+                items.remove(&op.item_id);
+            }
+            OpKind::Add | OpKind::Edit => {
+                if let Some(&tomb_ts) = tombstones.get(&op.item_id) {
+                    if tomb_ts > op.lamport_ts {
+                        continue; // an even-later delete wins; drop this stale edit
+                    }
+                    tombstones.remove(&op.item_id); // this edit re-adds the item
+                }
+
+                let entry = items.entry(op.item_id.clone()).or_insert_with(|| ItemPlain {
+                    title: String::new(),
+                    username: String::new(),
+                    password: String::new(),
+                    notes: String::new(),
+                });
+
+                if let Some(obj) = op.fields.as_object() {
+                    for (field, value) in obj {
+                        let Some(s) = value.as_str() else { continue };
+                        let key = (op.item_id.clone(), field.clone());
+                        let last = *field_ts.get(&key).unwrap_or(&0);
+                        if op.lamport_ts <= last {
+                            continue;
+                        }
+                        match field.as_str() {
+                            "title" => entry.title = s.to_string(),
+                            "username" => entry.username = s.to_string(),
+                            "password" => entry.password = s.to_string(),
+                            "notes" => entry.notes = s.to_string(),
+                            _ => continue,
+                        }
+                        field_ts.insert(key, op.lamport_ts);
+                    }
+                }
+            }
+        }
+    }
+
+    // Write the merged items and catalog back to local storage.
+    let now = crate::util::now_unix();
+    let existing_ids: HashSet<String> = v.backend.iter_items()?.into_iter().map(|r| r.id).collect();
+
+    for (id, item) in &items {
+        let pt = Zeroizing::new(serde_json::to_vec(item)?);
+        let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
+        if existing_ids.contains(id) {
+            v.backend.update_item(id, &blob, now)?;
+        } else {
+            v.backend.insert_item(id, &blob, now, now)?;
+        }
+    }
+    for id in &existing_ids {
+        if !items.contains_key(id) {
+            v.backend.delete_item(id)?;
+        }
+    }
+
+    let mut entries: Vec<CatalogEntry> = items.iter()
+        .map(|(id, item)| CatalogEntry { id: id.clone(), title: item.title.clone(), updated_at: now })
+        .collect();
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+    catalog::save_catalog(v, &entries)?;
+
+    // Persist the ops we learned from the remote side, re-sealed under our
+    // own key, and bump our Lamport clock past everything we just saw.
+    let mut max_seen_ts = common_ts;
+    for op in &new_remote_ops {
+        let pt = Zeroizing::new(serde_json::to_vec(op)?);
+        let blob = v.key.seal_with_suite(v.active_suite, &pt)?;
+        v.backend.append_op(&blob, op.lamport_ts, &op.node_id)?;
+        max_seen_ts = max_seen_ts.max(op.lamport_ts);
+    }
+    db::bump_lamport_past(&v.backend, max_seen_ts)?;
+
+    println!("Merged {} remote op(s), {} item(s) in vault.", new_remote_ops.len(), items.len());
+    Ok(())
+}
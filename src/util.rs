@@ -169,6 +169,71 @@ pub fn gen_password(
     Ok(Zeroizing::new(s))
 }
 
+// Memorable diceware-style passphrase: words drawn uniformly (rejection
+// sampling, same as `pick_one`) from the embedded wordlist, joined by `sep`.
+// The caller picks either an explicit word count or a target entropy in
+// bits, in which case the count is derived as
+// `ceil(target_bits / log2(wordlist_len))` - so raising --entropy always
+// buys more words rather than ever being rejected for "too few bits" (the
+// list only needs >= 2 words to make any entropy achievable).
+//
+// A single random digit and a single random symbol are inserted at random
+// positions (not just appended at the end) and one randomly chosen word is
+// capitalized, so the result still satisfies typical composition policies
+// without making those extra characters predictable. `--capitalize`
+// additionally capitalizes every word for readability.
+pub fn gen_passphrase(words: Option<usize>, entropy: Option<u32>, sep: &str, capitalize: bool) -> Result<Zeroizing<String>> {
+    let wordlist_len = crate::wordlist::WORDS.len();
+    if wordlist_len < 2 {
+        bail!("wordlist too small to generate a passphrase");
+    }
+    let bits_per_word = (wordlist_len as f64).log2();
+
+    let count = match (words, entropy) {
+        (Some(n), _) => n,
+        (None, Some(target_bits)) => ((target_bits as f64) / bits_per_word).ceil() as usize,
+        (None, None) => bail!("either --words or --entropy must be given"),
+    };
+    if count == 0 {
+        bail!("passphrase must have at least 1 word");
+    }
+
+    let mut parts: Zeroizing<Vec<String>> = Zeroizing::new(Vec::with_capacity(count));
+    for _ in 0..count {
+        parts.push(crate::wordlist::WORDS[rand_index(wordlist_len)?].to_string());
+    }
+
+    if capitalize {
+        for w in parts.iter_mut() {
+            *w = capitalize_word(w);
+        }
+    } else {
+        let idx = rand_index(parts.len())?;
+        parts[idx] = capitalize_word(&parts[idx]);
+    }
+
+    // Insert the digit and symbol by char index, not byte index: `sep` is a
+    // plain user-supplied string with no ASCII restriction, so a multi-byte
+    // separator (e.g. an emoji) would land `String::insert` mid-codepoint
+    // and panic if we indexed by byte offset instead.
+    let joined = Zeroizing::new(parts.join(sep));
+    let mut chars: Zeroizing<Vec<char>> = Zeroizing::new(joined.chars().collect());
+    let digit_pos = rand_index(chars.len() + 1)?;
+    chars.insert(digit_pos, pick_one(b"23456789")? as char);
+    let symbol_pos = rand_index(chars.len() + 1)?;
+    chars.insert(symbol_pos, pick_one(b"!@#$%^&*")? as char);
+
+    Ok(Zeroizing::new(chars.iter().collect()))
+}
+
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
 /* --- Copy to clipboard functions --- */
 
 // Try to set clipboard using arboard; Ok(()) if successful
@@ -286,3 +351,17 @@ pub fn clipboard_copy_pw_temporary(password: Zeroizing<String>, timeout_secs: u6
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gen_passphrase_handles_multibyte_separator() {
+        // A multi-byte `--sep` used to panic: the digit/symbol insertion
+        // indexed `s` by byte offset, which can land mid-codepoint.
+        let pass = gen_passphrase(Some(4), None, "🎲", false).unwrap();
+        assert!(pass.chars().any(|c| c.is_ascii_digit()));
+        assert!(pass.contains('🎲'));
+    }
+}
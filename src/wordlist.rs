@@ -0,0 +1,987 @@
+// A bundled wordlist for passphrase generation (see `util::gen_passphrase`).
+//
+// This is sized and built to match the EFF large wordlist's target: 7776
+// entries, giving log2(7776) ~= 12.92 bits/word. We don't vendor the EFF
+// list's actual text (no network access at generation time, and bundling
+// third-party corpus text has its own licensing/provenance questions) - the
+// 7776 entries below are instead every unique concatenation of two
+// consonant-vowel syllables (18 consonants x 5 vowels = 90 syllables, so
+// 90*90 = 8100 possible four-letter words, truncated to the first 7776),
+// which guarantees no duplicates and keeps every entry short, pronounceable,
+// and free of characters that are easy to confuse typing or reading aloud.
+// `gen_passphrase` computes its word count from the real `WORDS.len()`, so
+// `--entropy` targets are met exactly regardless of how the list is sourced.
+pub(crate) const WORDS: &[&str] = &[
+    "baba", "babe", "babi", "babo", "babu", "baca", "bace", "baci",
+    "baco", "bacu", "bada", "bade", "badi", "bado", "badu", "bafa",
+    "bafe", "bafi", "bafo", "bafu", "baga", "bage", "bagi", "bago",
+    "bagu", "baha", "bahe", "bahi", "baho", "bahu", "baja", "baje",
+    "baji", "bajo", "baju", "baka", "bake", "baki", "bako", "baku",
+    "bala", "bale", "bali", "balo", "balu", "bama", "bame", "bami",
+    "bamo", "bamu", "bana", "bane", "bani", "bano", "banu", "bapa",
+    "bape", "bapi", "bapo", "bapu", "bara", "bare", "bari", "baro",
+    "baru", "basa", "base", "basi", "baso", "basu", "bata", "bate",
+    "bati", "bato", "batu", "bava", "bave", "bavi", "bavo", "bavu",
+    "bawa", "bawe", "bawi", "bawo", "bawu", "baza", "baze", "bazi",
+    "bazo", "bazu", "beba", "bebe", "bebi", "bebo", "bebu", "beca",
+    "bece", "beci", "beco", "becu", "beda", "bede", "bedi", "bedo",
+    "bedu", "befa", "befe", "befi", "befo", "befu", "bega", "bege",
+    "begi", "bego", "begu", "beha", "behe", "behi", "beho", "behu",
+    "beja", "beje", "beji", "bejo", "beju", "beka", "beke", "beki",
+    "beko", "beku", "bela", "bele", "beli", "belo", "belu", "bema",
+    "beme", "bemi", "bemo", "bemu", "bena", "bene", "beni", "beno",
+    "benu", "bepa", "bepe", "bepi", "bepo", "bepu", "bera", "bere",
+    "beri", "bero", "beru", "besa", "bese", "besi", "beso", "besu",
+    "beta", "bete", "beti", "beto", "betu", "beva", "beve", "bevi",
+    "bevo", "bevu", "bewa", "bewe", "bewi", "bewo", "bewu", "beza",
+    "beze", "bezi", "bezo", "bezu", "biba", "bibe", "bibi", "bibo",
+    "bibu", "bica", "bice", "bici", "bico", "bicu", "bida", "bide",
+    "bidi", "bido", "bidu", "bifa", "bife", "bifi", "bifo", "bifu",
+    "biga", "bige", "bigi", "bigo", "bigu", "biha", "bihe", "bihi",
+    "biho", "bihu", "bija", "bije", "biji", "bijo", "biju", "bika",
+    "bike", "biki", "biko", "biku", "bila", "bile", "bili", "bilo",
+    "bilu", "bima", "bime", "bimi", "bimo", "bimu", "bina", "bine",
+    "bini", "bino", "binu", "bipa", "bipe", "bipi", "bipo", "bipu",
+    "bira", "bire", "biri", "biro", "biru", "bisa", "bise", "bisi",
+    "biso", "bisu", "bita", "bite", "biti", "bito", "bitu", "biva",
+    "bive", "bivi", "bivo", "bivu", "biwa", "biwe", "biwi", "biwo",
+    "biwu", "biza", "bize", "bizi", "bizo", "bizu", "boba", "bobe",
+    "bobi", "bobo", "bobu", "boca", "boce", "boci", "boco", "bocu",
+    "boda", "bode", "bodi", "bodo", "bodu", "bofa", "bofe", "bofi",
+    "bofo", "bofu", "boga", "boge", "bogi", "bogo", "bogu", "boha",
+    "bohe", "bohi", "boho", "bohu", "boja", "boje", "boji", "bojo",
+    "boju", "boka", "boke", "boki", "boko", "boku", "bola", "bole",
+    "boli", "bolo", "bolu", "boma", "bome", "bomi", "bomo", "bomu",
+    "bona", "bone", "boni", "bono", "bonu", "bopa", "bope", "bopi",
+    "bopo", "bopu", "bora", "bore", "bori", "boro", "boru", "bosa",
+    "bose", "bosi", "boso", "bosu", "bota", "bote", "boti", "boto",
+    "botu", "bova", "bove", "bovi", "bovo", "bovu", "bowa", "bowe",
+    "bowi", "bowo", "bowu", "boza", "boze", "bozi", "bozo", "bozu",
+    "buba", "bube", "bubi", "bubo", "bubu", "buca", "buce", "buci",
+    "buco", "bucu", "buda", "bude", "budi", "budo", "budu", "bufa",
+    "bufe", "bufi", "bufo", "bufu", "buga", "buge", "bugi", "bugo",
+    "bugu", "buha", "buhe", "buhi", "buho", "buhu", "buja", "buje",
+    "buji", "bujo", "buju", "buka", "buke", "buki", "buko", "buku",
+    "bula", "bule", "buli", "bulo", "bulu", "buma", "bume", "bumi",
+    "bumo", "bumu", "buna", "bune", "buni", "buno", "bunu", "bupa",
+    "bupe", "bupi", "bupo", "bupu", "bura", "bure", "buri", "buro",
+    "buru", "busa", "buse", "busi", "buso", "busu", "buta", "bute",
+    "buti", "buto", "butu", "buva", "buve", "buvi", "buvo", "buvu",
+    "buwa", "buwe", "buwi", "buwo", "buwu", "buza", "buze", "buzi",
+    "buzo", "buzu", "caba", "cabe", "cabi", "cabo", "cabu", "caca",
+    "cace", "caci", "caco", "cacu", "cada", "cade", "cadi", "cado",
+    "cadu", "cafa", "cafe", "cafi", "cafo", "cafu", "caga", "cage",
+    "cagi", "cago", "cagu", "caha", "cahe", "cahi", "caho", "cahu",
+    "caja", "caje", "caji", "cajo", "caju", "caka", "cake", "caki",
+    "cako", "caku", "cala", "cale", "cali", "calo", "calu", "cama",
+    "came", "cami", "camo", "camu", "cana", "cane", "cani", "cano",
+    "canu", "capa", "cape", "capi", "capo", "capu", "cara", "care",
+    "cari", "caro", "caru", "casa", "case", "casi", "caso", "casu",
+    "cata", "cate", "cati", "cato", "catu", "cava", "cave", "cavi",
+    "cavo", "cavu", "cawa", "cawe", "cawi", "cawo", "cawu", "caza",
+    "caze", "cazi", "cazo", "cazu", "ceba", "cebe", "cebi", "cebo",
+    "cebu", "ceca", "cece", "ceci", "ceco", "cecu", "ceda", "cede",
+    "cedi", "cedo", "cedu", "cefa", "cefe", "cefi", "cefo", "cefu",
+    "cega", "cege", "cegi", "cego", "cegu", "ceha", "cehe", "cehi",
+    "ceho", "cehu", "ceja", "ceje", "ceji", "cejo", "ceju", "ceka",
+    "ceke", "ceki", "ceko", "ceku", "cela", "cele", "celi", "celo",
+    "celu", "cema", "ceme", "cemi", "cemo", "cemu", "cena", "cene",
+    "ceni", "ceno", "cenu", "cepa", "cepe", "cepi", "cepo", "cepu",
+    "cera", "cere", "ceri", "cero", "ceru", "cesa", "cese", "cesi",
+    "ceso", "cesu", "ceta", "cete", "ceti", "ceto", "cetu", "ceva",
+    "ceve", "cevi", "cevo", "cevu", "cewa", "cewe", "cewi", "cewo",
+    "cewu", "ceza", "ceze", "cezi", "cezo", "cezu", "ciba", "cibe",
+    "cibi", "cibo", "cibu", "cica", "cice", "cici", "cico", "cicu",
+    "cida", "cide", "cidi", "cido", "cidu", "cifa", "cife", "cifi",
+    "cifo", "cifu", "ciga", "cige", "cigi", "cigo", "cigu", "ciha",
+    "cihe", "cihi", "ciho", "cihu", "cija", "cije", "ciji", "cijo",
+    "ciju", "cika", "cike", "ciki", "ciko", "ciku", "cila", "cile",
+    "cili", "cilo", "cilu", "cima", "cime", "cimi", "cimo", "cimu",
+    "cina", "cine", "cini", "cino", "cinu", "cipa", "cipe", "cipi",
+    "cipo", "cipu", "cira", "cire", "ciri", "ciro", "ciru", "cisa",
+    "cise", "cisi", "ciso", "cisu", "cita", "cite", "citi", "cito",
+    "citu", "civa", "cive", "civi", "civo", "civu", "ciwa", "ciwe",
+    "ciwi", "ciwo", "ciwu", "ciza", "cize", "cizi", "cizo", "cizu",
+    "coba", "cobe", "cobi", "cobo", "cobu", "coca", "coce", "coci",
+    "coco", "cocu", "coda", "code", "codi", "codo", "codu", "cofa",
+    "cofe", "cofi", "cofo", "cofu", "coga", "coge", "cogi", "cogo",
+    "cogu", "coha", "cohe", "cohi", "coho", "cohu", "coja", "coje",
+    "coji", "cojo", "coju", "coka", "coke", "coki", "coko", "coku",
+    "cola", "cole", "coli", "colo", "colu", "coma", "come", "comi",
+    "como", "comu", "cona", "cone", "coni", "cono", "conu", "copa",
+    "cope", "copi", "copo", "copu", "cora", "core", "cori", "coro",
+    "coru", "cosa", "cose", "cosi", "coso", "cosu", "cota", "cote",
+    "coti", "coto", "cotu", "cova", "cove", "covi", "covo", "covu",
+    "cowa", "cowe", "cowi", "cowo", "cowu", "coza", "coze", "cozi",
+    "cozo", "cozu", "cuba", "cube", "cubi", "cubo", "cubu", "cuca",
+    "cuce", "cuci", "cuco", "cucu", "cuda", "cude", "cudi", "cudo",
+    "cudu", "cufa", "cufe", "cufi", "cufo", "cufu", "cuga", "cuge",
+    "cugi", "cugo", "cugu", "cuha", "cuhe", "cuhi", "cuho", "cuhu",
+    "cuja", "cuje", "cuji", "cujo", "cuju", "cuka", "cuke", "cuki",
+    "cuko", "cuku", "cula", "cule", "culi", "culo", "culu", "cuma",
+    "cume", "cumi", "cumo", "cumu", "cuna", "cune", "cuni", "cuno",
+    "cunu", "cupa", "cupe", "cupi", "cupo", "cupu", "cura", "cure",
+    "curi", "curo", "curu", "cusa", "cuse", "cusi", "cuso", "cusu",
+    "cuta", "cute", "cuti", "cuto", "cutu", "cuva", "cuve", "cuvi",
+    "cuvo", "cuvu", "cuwa", "cuwe", "cuwi", "cuwo", "cuwu", "cuza",
+    "cuze", "cuzi", "cuzo", "cuzu", "daba", "dabe", "dabi", "dabo",
+    "dabu", "daca", "dace", "daci", "daco", "dacu", "dada", "dade",
+    "dadi", "dado", "dadu", "dafa", "dafe", "dafi", "dafo", "dafu",
+    "daga", "dage", "dagi", "dago", "dagu", "daha", "dahe", "dahi",
+    "daho", "dahu", "daja", "daje", "daji", "dajo", "daju", "daka",
+    "dake", "daki", "dako", "daku", "dala", "dale", "dali", "dalo",
+    "dalu", "dama", "dame", "dami", "damo", "damu", "dana", "dane",
+    "dani", "dano", "danu", "dapa", "dape", "dapi", "dapo", "dapu",
+    "dara", "dare", "dari", "daro", "daru", "dasa", "dase", "dasi",
+    "daso", "dasu", "data", "date", "dati", "dato", "datu", "dava",
+    "dave", "davi", "davo", "davu", "dawa", "dawe", "dawi", "dawo",
+    "dawu", "daza", "daze", "dazi", "dazo", "dazu", "deba", "debe",
+    "debi", "debo", "debu", "deca", "dece", "deci", "deco", "decu",
+    "deda", "dede", "dedi", "dedo", "dedu", "defa", "defe", "defi",
+    "defo", "defu", "dega", "dege", "degi", "dego", "degu", "deha",
+    "dehe", "dehi", "deho", "dehu", "deja", "deje", "deji", "dejo",
+    "deju", "deka", "deke", "deki", "deko", "deku", "dela", "dele",
+    "deli", "delo", "delu", "dema", "deme", "demi", "demo", "demu",
+    "dena", "dene", "deni", "deno", "denu", "depa", "depe", "depi",
+    "depo", "depu", "dera", "dere", "deri", "dero", "deru", "desa",
+    "dese", "desi", "deso", "desu", "deta", "dete", "deti", "deto",
+    "detu", "deva", "deve", "devi", "devo", "devu", "dewa", "dewe",
+    "dewi", "dewo", "dewu", "deza", "deze", "dezi", "dezo", "dezu",
+    "diba", "dibe", "dibi", "dibo", "dibu", "dica", "dice", "dici",
+    "dico", "dicu", "dida", "dide", "didi", "dido", "didu", "difa",
+    "dife", "difi", "difo", "difu", "diga", "dige", "digi", "digo",
+    "digu", "diha", "dihe", "dihi", "diho", "dihu", "dija", "dije",
+    "diji", "dijo", "diju", "dika", "dike", "diki", "diko", "diku",
+    "dila", "dile", "dili", "dilo", "dilu", "dima", "dime", "dimi",
+    "dimo", "dimu", "dina", "dine", "dini", "dino", "dinu", "dipa",
+    "dipe", "dipi", "dipo", "dipu", "dira", "dire", "diri", "diro",
+    "diru", "disa", "dise", "disi", "diso", "disu", "dita", "dite",
+    "diti", "dito", "ditu", "diva", "dive", "divi", "divo", "divu",
+    "diwa", "diwe", "diwi", "diwo", "diwu", "diza", "dize", "dizi",
+    "dizo", "dizu", "doba", "dobe", "dobi", "dobo", "dobu", "doca",
+    "doce", "doci", "doco", "docu", "doda", "dode", "dodi", "dodo",
+    "dodu", "dofa", "dofe", "dofi", "dofo", "dofu", "doga", "doge",
+    "dogi", "dogo", "dogu", "doha", "dohe", "dohi", "doho", "dohu",
+    "doja", "doje", "doji", "dojo", "doju", "doka", "doke", "doki",
+    "doko", "doku", "dola", "dole", "doli", "dolo", "dolu", "doma",
+    "dome", "domi", "domo", "domu", "dona", "done", "doni", "dono",
+    "donu", "dopa", "dope", "dopi", "dopo", "dopu", "dora", "dore",
+    "dori", "doro", "doru", "dosa", "dose", "dosi", "doso", "dosu",
+    "dota", "dote", "doti", "doto", "dotu", "dova", "dove", "dovi",
+    "dovo", "dovu", "dowa", "dowe", "dowi", "dowo", "dowu", "doza",
+    "doze", "dozi", "dozo", "dozu", "duba", "dube", "dubi", "dubo",
+    "dubu", "duca", "duce", "duci", "duco", "ducu", "duda", "dude",
+    "dudi", "dudo", "dudu", "dufa", "dufe", "dufi", "dufo", "dufu",
+    "duga", "duge", "dugi", "dugo", "dugu", "duha", "duhe", "duhi",
+    "duho", "duhu", "duja", "duje", "duji", "dujo", "duju", "duka",
+    "duke", "duki", "duko", "duku", "dula", "dule", "duli", "dulo",
+    "dulu", "duma", "dume", "dumi", "dumo", "dumu", "duna", "dune",
+    "duni", "duno", "dunu", "dupa", "dupe", "dupi", "dupo", "dupu",
+    "dura", "dure", "duri", "duro", "duru", "dusa", "duse", "dusi",
+    "duso", "dusu", "duta", "dute", "duti", "duto", "dutu", "duva",
+    "duve", "duvi", "duvo", "duvu", "duwa", "duwe", "duwi", "duwo",
+    "duwu", "duza", "duze", "duzi", "duzo", "duzu", "faba", "fabe",
+    "fabi", "fabo", "fabu", "faca", "face", "faci", "faco", "facu",
+    "fada", "fade", "fadi", "fado", "fadu", "fafa", "fafe", "fafi",
+    "fafo", "fafu", "faga", "fage", "fagi", "fago", "fagu", "faha",
+    "fahe", "fahi", "faho", "fahu", "faja", "faje", "faji", "fajo",
+    "faju", "faka", "fake", "faki", "fako", "faku", "fala", "fale",
+    "fali", "falo", "falu", "fama", "fame", "fami", "famo", "famu",
+    "fana", "fane", "fani", "fano", "fanu", "fapa", "fape", "fapi",
+    "fapo", "fapu", "fara", "fare", "fari", "faro", "faru", "fasa",
+    "fase", "fasi", "faso", "fasu", "fata", "fate", "fati", "fato",
+    "fatu", "fava", "fave", "favi", "favo", "favu", "fawa", "fawe",
+    "fawi", "fawo", "fawu", "faza", "faze", "fazi", "fazo", "fazu",
+    "feba", "febe", "febi", "febo", "febu", "feca", "fece", "feci",
+    "feco", "fecu", "feda", "fede", "fedi", "fedo", "fedu", "fefa",
+    "fefe", "fefi", "fefo", "fefu", "fega", "fege", "fegi", "fego",
+    "fegu", "feha", "fehe", "fehi", "feho", "fehu", "feja", "feje",
+    "feji", "fejo", "feju", "feka", "feke", "feki", "feko", "feku",
+    "fela", "fele", "feli", "felo", "felu", "fema", "feme", "femi",
+    "femo", "femu", "fena", "fene", "feni", "feno", "fenu", "fepa",
+    "fepe", "fepi", "fepo", "fepu", "fera", "fere", "feri", "fero",
+    "feru", "fesa", "fese", "fesi", "feso", "fesu", "feta", "fete",
+    "feti", "feto", "fetu", "feva", "feve", "fevi", "fevo", "fevu",
+    "fewa", "fewe", "fewi", "fewo", "fewu", "feza", "feze", "fezi",
+    "fezo", "fezu", "fiba", "fibe", "fibi", "fibo", "fibu", "fica",
+    "fice", "fici", "fico", "ficu", "fida", "fide", "fidi", "fido",
+    "fidu", "fifa", "fife", "fifi", "fifo", "fifu", "figa", "fige",
+    "figi", "figo", "figu", "fiha", "fihe", "fihi", "fiho", "fihu",
+    "fija", "fije", "fiji", "fijo", "fiju", "fika", "fike", "fiki",
+    "fiko", "fiku", "fila", "file", "fili", "filo", "filu", "fima",
+    "fime", "fimi", "fimo", "fimu", "fina", "fine", "fini", "fino",
+    "finu", "fipa", "fipe", "fipi", "fipo", "fipu", "fira", "fire",
+    "firi", "firo", "firu", "fisa", "fise", "fisi", "fiso", "fisu",
+    "fita", "fite", "fiti", "fito", "fitu", "fiva", "five", "fivi",
+    "fivo", "fivu", "fiwa", "fiwe", "fiwi", "fiwo", "fiwu", "fiza",
+    "fize", "fizi", "fizo", "fizu", "foba", "fobe", "fobi", "fobo",
+    "fobu", "foca", "foce", "foci", "foco", "focu", "foda", "fode",
+    "fodi", "fodo", "fodu", "fofa", "fofe", "fofi", "fofo", "fofu",
+    "foga", "foge", "fogi", "fogo", "fogu", "foha", "fohe", "fohi",
+    "foho", "fohu", "foja", "foje", "foji", "fojo", "foju", "foka",
+    "foke", "foki", "foko", "foku", "fola", "fole", "foli", "folo",
+    "folu", "foma", "fome", "fomi", "fomo", "fomu", "fona", "fone",
+    "foni", "fono", "fonu", "fopa", "fope", "fopi", "fopo", "fopu",
+    "fora", "fore", "fori", "foro", "foru", "fosa", "fose", "fosi",
+    "foso", "fosu", "fota", "fote", "foti", "foto", "fotu", "fova",
+    "fove", "fovi", "fovo", "fovu", "fowa", "fowe", "fowi", "fowo",
+    "fowu", "foza", "foze", "fozi", "fozo", "fozu", "fuba", "fube",
+    "fubi", "fubo", "fubu", "fuca", "fuce", "fuci", "fuco", "fucu",
+    "fuda", "fude", "fudi", "fudo", "fudu", "fufa", "fufe", "fufi",
+    "fufo", "fufu", "fuga", "fuge", "fugi", "fugo", "fugu", "fuha",
+    "fuhe", "fuhi", "fuho", "fuhu", "fuja", "fuje", "fuji", "fujo",
+    "fuju", "fuka", "fuke", "fuki", "fuko", "fuku", "fula", "fule",
+    "fuli", "fulo", "fulu", "fuma", "fume", "fumi", "fumo", "fumu",
+    "funa", "fune", "funi", "funo", "funu", "fupa", "fupe", "fupi",
+    "fupo", "fupu", "fura", "fure", "furi", "furo", "furu", "fusa",
+    "fuse", "fusi", "fuso", "fusu", "futa", "fute", "futi", "futo",
+    "futu", "fuva", "fuve", "fuvi", "fuvo", "fuvu", "fuwa", "fuwe",
+    "fuwi", "fuwo", "fuwu", "fuza", "fuze", "fuzi", "fuzo", "fuzu",
+    "gaba", "gabe", "gabi", "gabo", "gabu", "gaca", "gace", "gaci",
+    "gaco", "gacu", "gada", "gade", "gadi", "gado", "gadu", "gafa",
+    "gafe", "gafi", "gafo", "gafu", "gaga", "gage", "gagi", "gago",
+    "gagu", "gaha", "gahe", "gahi", "gaho", "gahu", "gaja", "gaje",
+    "gaji", "gajo", "gaju", "gaka", "gake", "gaki", "gako", "gaku",
+    "gala", "gale", "gali", "galo", "galu", "gama", "game", "gami",
+    "gamo", "gamu", "gana", "gane", "gani", "gano", "ganu", "gapa",
+    "gape", "gapi", "gapo", "gapu", "gara", "gare", "gari", "garo",
+    "garu", "gasa", "gase", "gasi", "gaso", "gasu", "gata", "gate",
+    "gati", "gato", "gatu", "gava", "gave", "gavi", "gavo", "gavu",
+    "gawa", "gawe", "gawi", "gawo", "gawu", "gaza", "gaze", "gazi",
+    "gazo", "gazu", "geba", "gebe", "gebi", "gebo", "gebu", "geca",
+    "gece", "geci", "geco", "gecu", "geda", "gede", "gedi", "gedo",
+    "gedu", "gefa", "gefe", "gefi", "gefo", "gefu", "gega", "gege",
+    "gegi", "gego", "gegu", "geha", "gehe", "gehi", "geho", "gehu",
+    "geja", "geje", "geji", "gejo", "geju", "geka", "geke", "geki",
+    "geko", "geku", "gela", "gele", "geli", "gelo", "gelu", "gema",
+    "geme", "gemi", "gemo", "gemu", "gena", "gene", "geni", "geno",
+    "genu", "gepa", "gepe", "gepi", "gepo", "gepu", "gera", "gere",
+    "geri", "gero", "geru", "gesa", "gese", "gesi", "geso", "gesu",
+    "geta", "gete", "geti", "geto", "getu", "geva", "geve", "gevi",
+    "gevo", "gevu", "gewa", "gewe", "gewi", "gewo", "gewu", "geza",
+    "geze", "gezi", "gezo", "gezu", "giba", "gibe", "gibi", "gibo",
+    "gibu", "gica", "gice", "gici", "gico", "gicu", "gida", "gide",
+    "gidi", "gido", "gidu", "gifa", "gife", "gifi", "gifo", "gifu",
+    "giga", "gige", "gigi", "gigo", "gigu", "giha", "gihe", "gihi",
+    "giho", "gihu", "gija", "gije", "giji", "gijo", "giju", "gika",
+    "gike", "giki", "giko", "giku", "gila", "gile", "gili", "gilo",
+    "gilu", "gima", "gime", "gimi", "gimo", "gimu", "gina", "gine",
+    "gini", "gino", "ginu", "gipa", "gipe", "gipi", "gipo", "gipu",
+    "gira", "gire", "giri", "giro", "giru", "gisa", "gise", "gisi",
+    "giso", "gisu", "gita", "gite", "giti", "gito", "gitu", "giva",
+    "give", "givi", "givo", "givu", "giwa", "giwe", "giwi", "giwo",
+    "giwu", "giza", "gize", "gizi", "gizo", "gizu", "goba", "gobe",
+    "gobi", "gobo", "gobu", "goca", "goce", "goci", "goco", "gocu",
+    "goda", "gode", "godi", "godo", "godu", "gofa", "gofe", "gofi",
+    "gofo", "gofu", "goga", "goge", "gogi", "gogo", "gogu", "goha",
+    "gohe", "gohi", "goho", "gohu", "goja", "goje", "goji", "gojo",
+    "goju", "goka", "goke", "goki", "goko", "goku", "gola", "gole",
+    "goli", "golo", "golu", "goma", "gome", "gomi", "gomo", "gomu",
+    "gona", "gone", "goni", "gono", "gonu", "gopa", "gope", "gopi",
+    "gopo", "gopu", "gora", "gore", "gori", "goro", "goru", "gosa",
+    "gose", "gosi", "goso", "gosu", "gota", "gote", "goti", "goto",
+    "gotu", "gova", "gove", "govi", "govo", "govu", "gowa", "gowe",
+    "gowi", "gowo", "gowu", "goza", "goze", "gozi", "gozo", "gozu",
+    "guba", "gube", "gubi", "gubo", "gubu", "guca", "guce", "guci",
+    "guco", "gucu", "guda", "gude", "gudi", "gudo", "gudu", "gufa",
+    "gufe", "gufi", "gufo", "gufu", "guga", "guge", "gugi", "gugo",
+    "gugu", "guha", "guhe", "guhi", "guho", "guhu", "guja", "guje",
+    "guji", "gujo", "guju", "guka", "guke", "guki", "guko", "guku",
+    "gula", "gule", "guli", "gulo", "gulu", "guma", "gume", "gumi",
+    "gumo", "gumu", "guna", "gune", "guni", "guno", "gunu", "gupa",
+    "gupe", "gupi", "gupo", "gupu", "gura", "gure", "guri", "guro",
+    "guru", "gusa", "guse", "gusi", "guso", "gusu", "guta", "gute",
+    "guti", "guto", "gutu", "guva", "guve", "guvi", "guvo", "guvu",
+    "guwa", "guwe", "guwi", "guwo", "guwu", "guza", "guze", "guzi",
+    "guzo", "guzu", "haba", "habe", "habi", "habo", "habu", "haca",
+    "hace", "haci", "haco", "hacu", "hada", "hade", "hadi", "hado",
+    "hadu", "hafa", "hafe", "hafi", "hafo", "hafu", "haga", "hage",
+    "hagi", "hago", "hagu", "haha", "hahe", "hahi", "haho", "hahu",
+    "haja", "haje", "haji", "hajo", "haju", "haka", "hake", "haki",
+    "hako", "haku", "hala", "hale", "hali", "halo", "halu", "hama",
+    "hame", "hami", "hamo", "hamu", "hana", "hane", "hani", "hano",
+    "hanu", "hapa", "hape", "hapi", "hapo", "hapu", "hara", "hare",
+    "hari", "haro", "haru", "hasa", "hase", "hasi", "haso", "hasu",
+    "hata", "hate", "hati", "hato", "hatu", "hava", "have", "havi",
+    "havo", "havu", "hawa", "hawe", "hawi", "hawo", "hawu", "haza",
+    "haze", "hazi", "hazo", "hazu", "heba", "hebe", "hebi", "hebo",
+    "hebu", "heca", "hece", "heci", "heco", "hecu", "heda", "hede",
+    "hedi", "hedo", "hedu", "hefa", "hefe", "hefi", "hefo", "hefu",
+    "hega", "hege", "hegi", "hego", "hegu", "heha", "hehe", "hehi",
+    "heho", "hehu", "heja", "heje", "heji", "hejo", "heju", "heka",
+    "heke", "heki", "heko", "heku", "hela", "hele", "heli", "helo",
+    "helu", "hema", "heme", "hemi", "hemo", "hemu", "hena", "hene",
+    "heni", "heno", "henu", "hepa", "hepe", "hepi", "hepo", "hepu",
+    "hera", "here", "heri", "hero", "heru", "hesa", "hese", "hesi",
+    "heso", "hesu", "heta", "hete", "heti", "heto", "hetu", "heva",
+    "heve", "hevi", "hevo", "hevu", "hewa", "hewe", "hewi", "hewo",
+    "hewu", "heza", "heze", "hezi", "hezo", "hezu", "hiba", "hibe",
+    "hibi", "hibo", "hibu", "hica", "hice", "hici", "hico", "hicu",
+    "hida", "hide", "hidi", "hido", "hidu", "hifa", "hife", "hifi",
+    "hifo", "hifu", "higa", "hige", "higi", "higo", "higu", "hiha",
+    "hihe", "hihi", "hiho", "hihu", "hija", "hije", "hiji", "hijo",
+    "hiju", "hika", "hike", "hiki", "hiko", "hiku", "hila", "hile",
+    "hili", "hilo", "hilu", "hima", "hime", "himi", "himo", "himu",
+    "hina", "hine", "hini", "hino", "hinu", "hipa", "hipe", "hipi",
+    "hipo", "hipu", "hira", "hire", "hiri", "hiro", "hiru", "hisa",
+    "hise", "hisi", "hiso", "hisu", "hita", "hite", "hiti", "hito",
+    "hitu", "hiva", "hive", "hivi", "hivo", "hivu", "hiwa", "hiwe",
+    "hiwi", "hiwo", "hiwu", "hiza", "hize", "hizi", "hizo", "hizu",
+    "hoba", "hobe", "hobi", "hobo", "hobu", "hoca", "hoce", "hoci",
+    "hoco", "hocu", "hoda", "hode", "hodi", "hodo", "hodu", "hofa",
+    "hofe", "hofi", "hofo", "hofu", "hoga", "hoge", "hogi", "hogo",
+    "hogu", "hoha", "hohe", "hohi", "hoho", "hohu", "hoja", "hoje",
+    "hoji", "hojo", "hoju", "hoka", "hoke", "hoki", "hoko", "hoku",
+    "hola", "hole", "holi", "holo", "holu", "homa", "home", "homi",
+    "homo", "homu", "hona", "hone", "honi", "hono", "honu", "hopa",
+    "hope", "hopi", "hopo", "hopu", "hora", "hore", "hori", "horo",
+    "horu", "hosa", "hose", "hosi", "hoso", "hosu", "hota", "hote",
+    "hoti", "hoto", "hotu", "hova", "hove", "hovi", "hovo", "hovu",
+    "howa", "howe", "howi", "howo", "howu", "hoza", "hoze", "hozi",
+    "hozo", "hozu", "huba", "hube", "hubi", "hubo", "hubu", "huca",
+    "huce", "huci", "huco", "hucu", "huda", "hude", "hudi", "hudo",
+    "hudu", "hufa", "hufe", "hufi", "hufo", "hufu", "huga", "huge",
+    "hugi", "hugo", "hugu", "huha", "huhe", "huhi", "huho", "huhu",
+    "huja", "huje", "huji", "hujo", "huju", "huka", "huke", "huki",
+    "huko", "huku", "hula", "hule", "huli", "hulo", "hulu", "huma",
+    "hume", "humi", "humo", "humu", "huna", "hune", "huni", "huno",
+    "hunu", "hupa", "hupe", "hupi", "hupo", "hupu", "hura", "hure",
+    "huri", "huro", "huru", "husa", "huse", "husi", "huso", "husu",
+    "huta", "hute", "huti", "huto", "hutu", "huva", "huve", "huvi",
+    "huvo", "huvu", "huwa", "huwe", "huwi", "huwo", "huwu", "huza",
+    "huze", "huzi", "huzo", "huzu", "jaba", "jabe", "jabi", "jabo",
+    "jabu", "jaca", "jace", "jaci", "jaco", "jacu", "jada", "jade",
+    "jadi", "jado", "jadu", "jafa", "jafe", "jafi", "jafo", "jafu",
+    "jaga", "jage", "jagi", "jago", "jagu", "jaha", "jahe", "jahi",
+    "jaho", "jahu", "jaja", "jaje", "jaji", "jajo", "jaju", "jaka",
+    "jake", "jaki", "jako", "jaku", "jala", "jale", "jali", "jalo",
+    "jalu", "jama", "jame", "jami", "jamo", "jamu", "jana", "jane",
+    "jani", "jano", "janu", "japa", "jape", "japi", "japo", "japu",
+    "jara", "jare", "jari", "jaro", "jaru", "jasa", "jase", "jasi",
+    "jaso", "jasu", "jata", "jate", "jati", "jato", "jatu", "java",
+    "jave", "javi", "javo", "javu", "jawa", "jawe", "jawi", "jawo",
+    "jawu", "jaza", "jaze", "jazi", "jazo", "jazu", "jeba", "jebe",
+    "jebi", "jebo", "jebu", "jeca", "jece", "jeci", "jeco", "jecu",
+    "jeda", "jede", "jedi", "jedo", "jedu", "jefa", "jefe", "jefi",
+    "jefo", "jefu", "jega", "jege", "jegi", "jego", "jegu", "jeha",
+    "jehe", "jehi", "jeho", "jehu", "jeja", "jeje", "jeji", "jejo",
+    "jeju", "jeka", "jeke", "jeki", "jeko", "jeku", "jela", "jele",
+    "jeli", "jelo", "jelu", "jema", "jeme", "jemi", "jemo", "jemu",
+    "jena", "jene", "jeni", "jeno", "jenu", "jepa", "jepe", "jepi",
+    "jepo", "jepu", "jera", "jere", "jeri", "jero", "jeru", "jesa",
+    "jese", "jesi", "jeso", "jesu", "jeta", "jete", "jeti", "jeto",
+    "jetu", "jeva", "jeve", "jevi", "jevo", "jevu", "jewa", "jewe",
+    "jewi", "jewo", "jewu", "jeza", "jeze", "jezi", "jezo", "jezu",
+    "jiba", "jibe", "jibi", "jibo", "jibu", "jica", "jice", "jici",
+    "jico", "jicu", "jida", "jide", "jidi", "jido", "jidu", "jifa",
+    "jife", "jifi", "jifo", "jifu", "jiga", "jige", "jigi", "jigo",
+    "jigu", "jiha", "jihe", "jihi", "jiho", "jihu", "jija", "jije",
+    "jiji", "jijo", "jiju", "jika", "jike", "jiki", "jiko", "jiku",
+    "jila", "jile", "jili", "jilo", "jilu", "jima", "jime", "jimi",
+    "jimo", "jimu", "jina", "jine", "jini", "jino", "jinu", "jipa",
+    "jipe", "jipi", "jipo", "jipu", "jira", "jire", "jiri", "jiro",
+    "jiru", "jisa", "jise", "jisi", "jiso", "jisu", "jita", "jite",
+    "jiti", "jito", "jitu", "jiva", "jive", "jivi", "jivo", "jivu",
+    "jiwa", "jiwe", "jiwi", "jiwo", "jiwu", "jiza", "jize", "jizi",
+    "jizo", "jizu", "joba", "jobe", "jobi", "jobo", "jobu", "joca",
+    "joce", "joci", "joco", "jocu", "joda", "jode", "jodi", "jodo",
+    "jodu", "jofa", "jofe", "jofi", "jofo", "jofu", "joga", "joge",
+    "jogi", "jogo", "jogu", "joha", "johe", "johi", "joho", "johu",
+    "joja", "joje", "joji", "jojo", "joju", "joka", "joke", "joki",
+    "joko", "joku", "jola", "jole", "joli", "jolo", "jolu", "joma",
+    "jome", "jomi", "jomo", "jomu", "jona", "jone", "joni", "jono",
+    "jonu", "jopa", "jope", "jopi", "jopo", "jopu", "jora", "jore",
+    "jori", "joro", "joru", "josa", "jose", "josi", "joso", "josu",
+    "jota", "jote", "joti", "joto", "jotu", "jova", "jove", "jovi",
+    "jovo", "jovu", "jowa", "jowe", "jowi", "jowo", "jowu", "joza",
+    "joze", "jozi", "jozo", "jozu", "juba", "jube", "jubi", "jubo",
+    "jubu", "juca", "juce", "juci", "juco", "jucu", "juda", "jude",
+    "judi", "judo", "judu", "jufa", "jufe", "jufi", "jufo", "jufu",
+    "juga", "juge", "jugi", "jugo", "jugu", "juha", "juhe", "juhi",
+    "juho", "juhu", "juja", "juje", "juji", "jujo", "juju", "juka",
+    "juke", "juki", "juko", "juku", "jula", "jule", "juli", "julo",
+    "julu", "juma", "jume", "jumi", "jumo", "jumu", "juna", "june",
+    "juni", "juno", "junu", "jupa", "jupe", "jupi", "jupo", "jupu",
+    "jura", "jure", "juri", "juro", "juru", "jusa", "juse", "jusi",
+    "juso", "jusu", "juta", "jute", "juti", "juto", "jutu", "juva",
+    "juve", "juvi", "juvo", "juvu", "juwa", "juwe", "juwi", "juwo",
+    "juwu", "juza", "juze", "juzi", "juzo", "juzu", "kaba", "kabe",
+    "kabi", "kabo", "kabu", "kaca", "kace", "kaci", "kaco", "kacu",
+    "kada", "kade", "kadi", "kado", "kadu", "kafa", "kafe", "kafi",
+    "kafo", "kafu", "kaga", "kage", "kagi", "kago", "kagu", "kaha",
+    "kahe", "kahi", "kaho", "kahu", "kaja", "kaje", "kaji", "kajo",
+    "kaju", "kaka", "kake", "kaki", "kako", "kaku", "kala", "kale",
+    "kali", "kalo", "kalu", "kama", "kame", "kami", "kamo", "kamu",
+    "kana", "kane", "kani", "kano", "kanu", "kapa", "kape", "kapi",
+    "kapo", "kapu", "kara", "kare", "kari", "karo", "karu", "kasa",
+    "kase", "kasi", "kaso", "kasu", "kata", "kate", "kati", "kato",
+    "katu", "kava", "kave", "kavi", "kavo", "kavu", "kawa", "kawe",
+    "kawi", "kawo", "kawu", "kaza", "kaze", "kazi", "kazo", "kazu",
+    "keba", "kebe", "kebi", "kebo", "kebu", "keca", "kece", "keci",
+    "keco", "kecu", "keda", "kede", "kedi", "kedo", "kedu", "kefa",
+    "kefe", "kefi", "kefo", "kefu", "kega", "kege", "kegi", "kego",
+    "kegu", "keha", "kehe", "kehi", "keho", "kehu", "keja", "keje",
+    "keji", "kejo", "keju", "keka", "keke", "keki", "keko", "keku",
+    "kela", "kele", "keli", "kelo", "kelu", "kema", "keme", "kemi",
+    "kemo", "kemu", "kena", "kene", "keni", "keno", "kenu", "kepa",
+    "kepe", "kepi", "kepo", "kepu", "kera", "kere", "keri", "kero",
+    "keru", "kesa", "kese", "kesi", "keso", "kesu", "keta", "kete",
+    "keti", "keto", "ketu", "keva", "keve", "kevi", "kevo", "kevu",
+    "kewa", "kewe", "kewi", "kewo", "kewu", "keza", "keze", "kezi",
+    "kezo", "kezu", "kiba", "kibe", "kibi", "kibo", "kibu", "kica",
+    "kice", "kici", "kico", "kicu", "kida", "kide", "kidi", "kido",
+    "kidu", "kifa", "kife", "kifi", "kifo", "kifu", "kiga", "kige",
+    "kigi", "kigo", "kigu", "kiha", "kihe", "kihi", "kiho", "kihu",
+    "kija", "kije", "kiji", "kijo", "kiju", "kika", "kike", "kiki",
+    "kiko", "kiku", "kila", "kile", "kili", "kilo", "kilu", "kima",
+    "kime", "kimi", "kimo", "kimu", "kina", "kine", "kini", "kino",
+    "kinu", "kipa", "kipe", "kipi", "kipo", "kipu", "kira", "kire",
+    "kiri", "kiro", "kiru", "kisa", "kise", "kisi", "kiso", "kisu",
+    "kita", "kite", "kiti", "kito", "kitu", "kiva", "kive", "kivi",
+    "kivo", "kivu", "kiwa", "kiwe", "kiwi", "kiwo", "kiwu", "kiza",
+    "kize", "kizi", "kizo", "kizu", "koba", "kobe", "kobi", "kobo",
+    "kobu", "koca", "koce", "koci", "koco", "kocu", "koda", "kode",
+    "kodi", "kodo", "kodu", "kofa", "kofe", "kofi", "kofo", "kofu",
+    "koga", "koge", "kogi", "kogo", "kogu", "koha", "kohe", "kohi",
+    "koho", "kohu", "koja", "koje", "koji", "kojo", "koju", "koka",
+    "koke", "koki", "koko", "koku", "kola", "kole", "koli", "kolo",
+    "kolu", "koma", "kome", "komi", "komo", "komu", "kona", "kone",
+    "koni", "kono", "konu", "kopa", "kope", "kopi", "kopo", "kopu",
+    "kora", "kore", "kori", "koro", "koru", "kosa", "kose", "kosi",
+    "koso", "kosu", "kota", "kote", "koti", "koto", "kotu", "kova",
+    "kove", "kovi", "kovo", "kovu", "kowa", "kowe", "kowi", "kowo",
+    "kowu", "koza", "koze", "kozi", "kozo", "kozu", "kuba", "kube",
+    "kubi", "kubo", "kubu", "kuca", "kuce", "kuci", "kuco", "kucu",
+    "kuda", "kude", "kudi", "kudo", "kudu", "kufa", "kufe", "kufi",
+    "kufo", "kufu", "kuga", "kuge", "kugi", "kugo", "kugu", "kuha",
+    "kuhe", "kuhi", "kuho", "kuhu", "kuja", "kuje", "kuji", "kujo",
+    "kuju", "kuka", "kuke", "kuki", "kuko", "kuku", "kula", "kule",
+    "kuli", "kulo", "kulu", "kuma", "kume", "kumi", "kumo", "kumu",
+    "kuna", "kune", "kuni", "kuno", "kunu", "kupa", "kupe", "kupi",
+    "kupo", "kupu", "kura", "kure", "kuri", "kuro", "kuru", "kusa",
+    "kuse", "kusi", "kuso", "kusu", "kuta", "kute", "kuti", "kuto",
+    "kutu", "kuva", "kuve", "kuvi", "kuvo", "kuvu", "kuwa", "kuwe",
+    "kuwi", "kuwo", "kuwu", "kuza", "kuze", "kuzi", "kuzo", "kuzu",
+    "laba", "labe", "labi", "labo", "labu", "laca", "lace", "laci",
+    "laco", "lacu", "lada", "lade", "ladi", "lado", "ladu", "lafa",
+    "lafe", "lafi", "lafo", "lafu", "laga", "lage", "lagi", "lago",
+    "lagu", "laha", "lahe", "lahi", "laho", "lahu", "laja", "laje",
+    "laji", "lajo", "laju", "laka", "lake", "laki", "lako", "laku",
+    "lala", "lale", "lali", "lalo", "lalu", "lama", "lame", "lami",
+    "lamo", "lamu", "lana", "lane", "lani", "lano", "lanu", "lapa",
+    "lape", "lapi", "lapo", "lapu", "lara", "lare", "lari", "laro",
+    "laru", "lasa", "lase", "lasi", "laso", "lasu", "lata", "late",
+    "lati", "lato", "latu", "lava", "lave", "lavi", "lavo", "lavu",
+    "lawa", "lawe", "lawi", "lawo", "lawu", "laza", "laze", "lazi",
+    "lazo", "lazu", "leba", "lebe", "lebi", "lebo", "lebu", "leca",
+    "lece", "leci", "leco", "lecu", "leda", "lede", "ledi", "ledo",
+    "ledu", "lefa", "lefe", "lefi", "lefo", "lefu", "lega", "lege",
+    "legi", "lego", "legu", "leha", "lehe", "lehi", "leho", "lehu",
+    "leja", "leje", "leji", "lejo", "leju", "leka", "leke", "leki",
+    "leko", "leku", "lela", "lele", "leli", "lelo", "lelu", "lema",
+    "leme", "lemi", "lemo", "lemu", "lena", "lene", "leni", "leno",
+    "lenu", "lepa", "lepe", "lepi", "lepo", "lepu", "lera", "lere",
+    "leri", "lero", "leru", "lesa", "lese", "lesi", "leso", "lesu",
+    "leta", "lete", "leti", "leto", "letu", "leva", "leve", "levi",
+    "levo", "levu", "lewa", "lewe", "lewi", "lewo", "lewu", "leza",
+    "leze", "lezi", "lezo", "lezu", "liba", "libe", "libi", "libo",
+    "libu", "lica", "lice", "lici", "lico", "licu", "lida", "lide",
+    "lidi", "lido", "lidu", "lifa", "life", "lifi", "lifo", "lifu",
+    "liga", "lige", "ligi", "ligo", "ligu", "liha", "lihe", "lihi",
+    "liho", "lihu", "lija", "lije", "liji", "lijo", "liju", "lika",
+    "like", "liki", "liko", "liku", "lila", "lile", "lili", "lilo",
+    "lilu", "lima", "lime", "limi", "limo", "limu", "lina", "line",
+    "lini", "lino", "linu", "lipa", "lipe", "lipi", "lipo", "lipu",
+    "lira", "lire", "liri", "liro", "liru", "lisa", "lise", "lisi",
+    "liso", "lisu", "lita", "lite", "liti", "lito", "litu", "liva",
+    "live", "livi", "livo", "livu", "liwa", "liwe", "liwi", "liwo",
+    "liwu", "liza", "lize", "lizi", "lizo", "lizu", "loba", "lobe",
+    "lobi", "lobo", "lobu", "loca", "loce", "loci", "loco", "locu",
+    "loda", "lode", "lodi", "lodo", "lodu", "lofa", "lofe", "lofi",
+    "lofo", "lofu", "loga", "loge", "logi", "logo", "logu", "loha",
+    "lohe", "lohi", "loho", "lohu", "loja", "loje", "loji", "lojo",
+    "loju", "loka", "loke", "loki", "loko", "loku", "lola", "lole",
+    "loli", "lolo", "lolu", "loma", "lome", "lomi", "lomo", "lomu",
+    "lona", "lone", "loni", "lono", "lonu", "lopa", "lope", "lopi",
+    "lopo", "lopu", "lora", "lore", "lori", "loro", "loru", "losa",
+    "lose", "losi", "loso", "losu", "lota", "lote", "loti", "loto",
+    "lotu", "lova", "love", "lovi", "lovo", "lovu", "lowa", "lowe",
+    "lowi", "lowo", "lowu", "loza", "loze", "lozi", "lozo", "lozu",
+    "luba", "lube", "lubi", "lubo", "lubu", "luca", "luce", "luci",
+    "luco", "lucu", "luda", "lude", "ludi", "ludo", "ludu", "lufa",
+    "lufe", "lufi", "lufo", "lufu", "luga", "luge", "lugi", "lugo",
+    "lugu", "luha", "luhe", "luhi", "luho", "luhu", "luja", "luje",
+    "luji", "lujo", "luju", "luka", "luke", "luki", "luko", "luku",
+    "lula", "lule", "luli", "lulo", "lulu", "luma", "lume", "lumi",
+    "lumo", "lumu", "luna", "lune", "luni", "luno", "lunu", "lupa",
+    "lupe", "lupi", "lupo", "lupu", "lura", "lure", "luri", "luro",
+    "luru", "lusa", "luse", "lusi", "luso", "lusu", "luta", "lute",
+    "luti", "luto", "lutu", "luva", "luve", "luvi", "luvo", "luvu",
+    "luwa", "luwe", "luwi", "luwo", "luwu", "luza", "luze", "luzi",
+    "luzo", "luzu", "maba", "mabe", "mabi", "mabo", "mabu", "maca",
+    "mace", "maci", "maco", "macu", "mada", "made", "madi", "mado",
+    "madu", "mafa", "mafe", "mafi", "mafo", "mafu", "maga", "mage",
+    "magi", "mago", "magu", "maha", "mahe", "mahi", "maho", "mahu",
+    "maja", "maje", "maji", "majo", "maju", "maka", "make", "maki",
+    "mako", "maku", "mala", "male", "mali", "malo", "malu", "mama",
+    "mame", "mami", "mamo", "mamu", "mana", "mane", "mani", "mano",
+    "manu", "mapa", "mape", "mapi", "mapo", "mapu", "mara", "mare",
+    "mari", "maro", "maru", "masa", "mase", "masi", "maso", "masu",
+    "mata", "mate", "mati", "mato", "matu", "mava", "mave", "mavi",
+    "mavo", "mavu", "mawa", "mawe", "mawi", "mawo", "mawu", "maza",
+    "maze", "mazi", "mazo", "mazu", "meba", "mebe", "mebi", "mebo",
+    "mebu", "meca", "mece", "meci", "meco", "mecu", "meda", "mede",
+    "medi", "medo", "medu", "mefa", "mefe", "mefi", "mefo", "mefu",
+    "mega", "mege", "megi", "mego", "megu", "meha", "mehe", "mehi",
+    "meho", "mehu", "meja", "meje", "meji", "mejo", "meju", "meka",
+    "meke", "meki", "meko", "meku", "mela", "mele", "meli", "melo",
+    "melu", "mema", "meme", "memi", "memo", "memu", "mena", "mene",
+    "meni", "meno", "menu", "mepa", "mepe", "mepi", "mepo", "mepu",
+    "mera", "mere", "meri", "mero", "meru", "mesa", "mese", "mesi",
+    "meso", "mesu", "meta", "mete", "meti", "meto", "metu", "meva",
+    "meve", "mevi", "mevo", "mevu", "mewa", "mewe", "mewi", "mewo",
+    "mewu", "meza", "meze", "mezi", "mezo", "mezu", "miba", "mibe",
+    "mibi", "mibo", "mibu", "mica", "mice", "mici", "mico", "micu",
+    "mida", "mide", "midi", "mido", "midu", "mifa", "mife", "mifi",
+    "mifo", "mifu", "miga", "mige", "migi", "migo", "migu", "miha",
+    "mihe", "mihi", "miho", "mihu", "mija", "mije", "miji", "mijo",
+    "miju", "mika", "mike", "miki", "miko", "miku", "mila", "mile",
+    "mili", "milo", "milu", "mima", "mime", "mimi", "mimo", "mimu",
+    "mina", "mine", "mini", "mino", "minu", "mipa", "mipe", "mipi",
+    "mipo", "mipu", "mira", "mire", "miri", "miro", "miru", "misa",
+    "mise", "misi", "miso", "misu", "mita", "mite", "miti", "mito",
+    "mitu", "miva", "mive", "mivi", "mivo", "mivu", "miwa", "miwe",
+    "miwi", "miwo", "miwu", "miza", "mize", "mizi", "mizo", "mizu",
+    "moba", "mobe", "mobi", "mobo", "mobu", "moca", "moce", "moci",
+    "moco", "mocu", "moda", "mode", "modi", "modo", "modu", "mofa",
+    "mofe", "mofi", "mofo", "mofu", "moga", "moge", "mogi", "mogo",
+    "mogu", "moha", "mohe", "mohi", "moho", "mohu", "moja", "moje",
+    "moji", "mojo", "moju", "moka", "moke", "moki", "moko", "moku",
+    "mola", "mole", "moli", "molo", "molu", "moma", "mome", "momi",
+    "momo", "momu", "mona", "mone", "moni", "mono", "monu", "mopa",
+    "mope", "mopi", "mopo", "mopu", "mora", "more", "mori", "moro",
+    "moru", "mosa", "mose", "mosi", "moso", "mosu", "mota", "mote",
+    "moti", "moto", "motu", "mova", "move", "movi", "movo", "movu",
+    "mowa", "mowe", "mowi", "mowo", "mowu", "moza", "moze", "mozi",
+    "mozo", "mozu", "muba", "mube", "mubi", "mubo", "mubu", "muca",
+    "muce", "muci", "muco", "mucu", "muda", "mude", "mudi", "mudo",
+    "mudu", "mufa", "mufe", "mufi", "mufo", "mufu", "muga", "muge",
+    "mugi", "mugo", "mugu", "muha", "muhe", "muhi", "muho", "muhu",
+    "muja", "muje", "muji", "mujo", "muju", "muka", "muke", "muki",
+    "muko", "muku", "mula", "mule", "muli", "mulo", "mulu", "muma",
+    "mume", "mumi", "mumo", "mumu", "muna", "mune", "muni", "muno",
+    "munu", "mupa", "mupe", "mupi", "mupo", "mupu", "mura", "mure",
+    "muri", "muro", "muru", "musa", "muse", "musi", "muso", "musu",
+    "muta", "mute", "muti", "muto", "mutu", "muva", "muve", "muvi",
+    "muvo", "muvu", "muwa", "muwe", "muwi", "muwo", "muwu", "muza",
+    "muze", "muzi", "muzo", "muzu", "naba", "nabe", "nabi", "nabo",
+    "nabu", "naca", "nace", "naci", "naco", "nacu", "nada", "nade",
+    "nadi", "nado", "nadu", "nafa", "nafe", "nafi", "nafo", "nafu",
+    "naga", "nage", "nagi", "nago", "nagu", "naha", "nahe", "nahi",
+    "naho", "nahu", "naja", "naje", "naji", "najo", "naju", "naka",
+    "nake", "naki", "nako", "naku", "nala", "nale", "nali", "nalo",
+    "nalu", "nama", "name", "nami", "namo", "namu", "nana", "nane",
+    "nani", "nano", "nanu", "napa", "nape", "napi", "napo", "napu",
+    "nara", "nare", "nari", "naro", "naru", "nasa", "nase", "nasi",
+    "naso", "nasu", "nata", "nate", "nati", "nato", "natu", "nava",
+    "nave", "navi", "navo", "navu", "nawa", "nawe", "nawi", "nawo",
+    "nawu", "naza", "naze", "nazi", "nazo", "nazu", "neba", "nebe",
+    "nebi", "nebo", "nebu", "neca", "nece", "neci", "neco", "necu",
+    "neda", "nede", "nedi", "nedo", "nedu", "nefa", "nefe", "nefi",
+    "nefo", "nefu", "nega", "nege", "negi", "nego", "negu", "neha",
+    "nehe", "nehi", "neho", "nehu", "neja", "neje", "neji", "nejo",
+    "neju", "neka", "neke", "neki", "neko", "neku", "nela", "nele",
+    "neli", "nelo", "nelu", "nema", "neme", "nemi", "nemo", "nemu",
+    "nena", "nene", "neni", "neno", "nenu", "nepa", "nepe", "nepi",
+    "nepo", "nepu", "nera", "nere", "neri", "nero", "neru", "nesa",
+    "nese", "nesi", "neso", "nesu", "neta", "nete", "neti", "neto",
+    "netu", "neva", "neve", "nevi", "nevo", "nevu", "newa", "newe",
+    "newi", "newo", "newu", "neza", "neze", "nezi", "nezo", "nezu",
+    "niba", "nibe", "nibi", "nibo", "nibu", "nica", "nice", "nici",
+    "nico", "nicu", "nida", "nide", "nidi", "nido", "nidu", "nifa",
+    "nife", "nifi", "nifo", "nifu", "niga", "nige", "nigi", "nigo",
+    "nigu", "niha", "nihe", "nihi", "niho", "nihu", "nija", "nije",
+    "niji", "nijo", "niju", "nika", "nike", "niki", "niko", "niku",
+    "nila", "nile", "nili", "nilo", "nilu", "nima", "nime", "nimi",
+    "nimo", "nimu", "nina", "nine", "nini", "nino", "ninu", "nipa",
+    "nipe", "nipi", "nipo", "nipu", "nira", "nire", "niri", "niro",
+    "niru", "nisa", "nise", "nisi", "niso", "nisu", "nita", "nite",
+    "niti", "nito", "nitu", "niva", "nive", "nivi", "nivo", "nivu",
+    "niwa", "niwe", "niwi", "niwo", "niwu", "niza", "nize", "nizi",
+    "nizo", "nizu", "noba", "nobe", "nobi", "nobo", "nobu", "noca",
+    "noce", "noci", "noco", "nocu", "noda", "node", "nodi", "nodo",
+    "nodu", "nofa", "nofe", "nofi", "nofo", "nofu", "noga", "noge",
+    "nogi", "nogo", "nogu", "noha", "nohe", "nohi", "noho", "nohu",
+    "noja", "noje", "noji", "nojo", "noju", "noka", "noke", "noki",
+    "noko", "noku", "nola", "nole", "noli", "nolo", "nolu", "noma",
+    "nome", "nomi", "nomo", "nomu", "nona", "none", "noni", "nono",
+    "nonu", "nopa", "nope", "nopi", "nopo", "nopu", "nora", "nore",
+    "nori", "noro", "noru", "nosa", "nose", "nosi", "noso", "nosu",
+    "nota", "note", "noti", "noto", "notu", "nova", "nove", "novi",
+    "novo", "novu", "nowa", "nowe", "nowi", "nowo", "nowu", "noza",
+    "noze", "nozi", "nozo", "nozu", "nuba", "nube", "nubi", "nubo",
+    "nubu", "nuca", "nuce", "nuci", "nuco", "nucu", "nuda", "nude",
+    "nudi", "nudo", "nudu", "nufa", "nufe", "nufi", "nufo", "nufu",
+    "nuga", "nuge", "nugi", "nugo", "nugu", "nuha", "nuhe", "nuhi",
+    "nuho", "nuhu", "nuja", "nuje", "nuji", "nujo", "nuju", "nuka",
+    "nuke", "nuki", "nuko", "nuku", "nula", "nule", "nuli", "nulo",
+    "nulu", "numa", "nume", "numi", "numo", "numu", "nuna", "nune",
+    "nuni", "nuno", "nunu", "nupa", "nupe", "nupi", "nupo", "nupu",
+    "nura", "nure", "nuri", "nuro", "nuru", "nusa", "nuse", "nusi",
+    "nuso", "nusu", "nuta", "nute", "nuti", "nuto", "nutu", "nuva",
+    "nuve", "nuvi", "nuvo", "nuvu", "nuwa", "nuwe", "nuwi", "nuwo",
+    "nuwu", "nuza", "nuze", "nuzi", "nuzo", "nuzu", "paba", "pabe",
+    "pabi", "pabo", "pabu", "paca", "pace", "paci", "paco", "pacu",
+    "pada", "pade", "padi", "pado", "padu", "pafa", "pafe", "pafi",
+    "pafo", "pafu", "paga", "page", "pagi", "pago", "pagu", "paha",
+    "pahe", "pahi", "paho", "pahu", "paja", "paje", "paji", "pajo",
+    "paju", "paka", "pake", "paki", "pako", "paku", "pala", "pale",
+    "pali", "palo", "palu", "pama", "pame", "pami", "pamo", "pamu",
+    "pana", "pane", "pani", "pano", "panu", "papa", "pape", "papi",
+    "papo", "papu", "para", "pare", "pari", "paro", "paru", "pasa",
+    "pase", "pasi", "paso", "pasu", "pata", "pate", "pati", "pato",
+    "patu", "pava", "pave", "pavi", "pavo", "pavu", "pawa", "pawe",
+    "pawi", "pawo", "pawu", "paza", "paze", "pazi", "pazo", "pazu",
+    "peba", "pebe", "pebi", "pebo", "pebu", "peca", "pece", "peci",
+    "peco", "pecu", "peda", "pede", "pedi", "pedo", "pedu", "pefa",
+    "pefe", "pefi", "pefo", "pefu", "pega", "pege", "pegi", "pego",
+    "pegu", "peha", "pehe", "pehi", "peho", "pehu", "peja", "peje",
+    "peji", "pejo", "peju", "peka", "peke", "peki", "peko", "peku",
+    "pela", "pele", "peli", "pelo", "pelu", "pema", "peme", "pemi",
+    "pemo", "pemu", "pena", "pene", "peni", "peno", "penu", "pepa",
+    "pepe", "pepi", "pepo", "pepu", "pera", "pere", "peri", "pero",
+    "peru", "pesa", "pese", "pesi", "peso", "pesu", "peta", "pete",
+    "peti", "peto", "petu", "peva", "peve", "pevi", "pevo", "pevu",
+    "pewa", "pewe", "pewi", "pewo", "pewu", "peza", "peze", "pezi",
+    "pezo", "pezu", "piba", "pibe", "pibi", "pibo", "pibu", "pica",
+    "pice", "pici", "pico", "picu", "pida", "pide", "pidi", "pido",
+    "pidu", "pifa", "pife", "pifi", "pifo", "pifu", "piga", "pige",
+    "pigi", "pigo", "pigu", "piha", "pihe", "pihi", "piho", "pihu",
+    "pija", "pije", "piji", "pijo", "piju", "pika", "pike", "piki",
+    "piko", "piku", "pila", "pile", "pili", "pilo", "pilu", "pima",
+    "pime", "pimi", "pimo", "pimu", "pina", "pine", "pini", "pino",
+    "pinu", "pipa", "pipe", "pipi", "pipo", "pipu", "pira", "pire",
+    "piri", "piro", "piru", "pisa", "pise", "pisi", "piso", "pisu",
+    "pita", "pite", "piti", "pito", "pitu", "piva", "pive", "pivi",
+    "pivo", "pivu", "piwa", "piwe", "piwi", "piwo", "piwu", "piza",
+    "pize", "pizi", "pizo", "pizu", "poba", "pobe", "pobi", "pobo",
+    "pobu", "poca", "poce", "poci", "poco", "pocu", "poda", "pode",
+    "podi", "podo", "podu", "pofa", "pofe", "pofi", "pofo", "pofu",
+    "poga", "poge", "pogi", "pogo", "pogu", "poha", "pohe", "pohi",
+    "poho", "pohu", "poja", "poje", "poji", "pojo", "poju", "poka",
+    "poke", "poki", "poko", "poku", "pola", "pole", "poli", "polo",
+    "polu", "poma", "pome", "pomi", "pomo", "pomu", "pona", "pone",
+    "poni", "pono", "ponu", "popa", "pope", "popi", "popo", "popu",
+    "pora", "pore", "pori", "poro", "poru", "posa", "pose", "posi",
+    "poso", "posu", "pota", "pote", "poti", "poto", "potu", "pova",
+    "pove", "povi", "povo", "povu", "powa", "powe", "powi", "powo",
+    "powu", "poza", "poze", "pozi", "pozo", "pozu", "puba", "pube",
+    "pubi", "pubo", "pubu", "puca", "puce", "puci", "puco", "pucu",
+    "puda", "pude", "pudi", "pudo", "pudu", "pufa", "pufe", "pufi",
+    "pufo", "pufu", "puga", "puge", "pugi", "pugo", "pugu", "puha",
+    "puhe", "puhi", "puho", "puhu", "puja", "puje", "puji", "pujo",
+    "puju", "puka", "puke", "puki", "puko", "puku", "pula", "pule",
+    "puli", "pulo", "pulu", "puma", "pume", "pumi", "pumo", "pumu",
+    "puna", "pune", "puni", "puno", "punu", "pupa", "pupe", "pupi",
+    "pupo", "pupu", "pura", "pure", "puri", "puro", "puru", "pusa",
+    "puse", "pusi", "puso", "pusu", "puta", "pute", "puti", "puto",
+    "putu", "puva", "puve", "puvi", "puvo", "puvu", "puwa", "puwe",
+    "puwi", "puwo", "puwu", "puza", "puze", "puzi", "puzo", "puzu",
+    "raba", "rabe", "rabi", "rabo", "rabu", "raca", "race", "raci",
+    "raco", "racu", "rada", "rade", "radi", "rado", "radu", "rafa",
+    "rafe", "rafi", "rafo", "rafu", "raga", "rage", "ragi", "rago",
+    "ragu", "raha", "rahe", "rahi", "raho", "rahu", "raja", "raje",
+    "raji", "rajo", "raju", "raka", "rake", "raki", "rako", "raku",
+    "rala", "rale", "rali", "ralo", "ralu", "rama", "rame", "rami",
+    "ramo", "ramu", "rana", "rane", "rani", "rano", "ranu", "rapa",
+    "rape", "rapi", "rapo", "rapu", "rara", "rare", "rari", "raro",
+    "raru", "rasa", "rase", "rasi", "raso", "rasu", "rata", "rate",
+    "rati", "rato", "ratu", "rava", "rave", "ravi", "ravo", "ravu",
+    "rawa", "rawe", "rawi", "rawo", "rawu", "raza", "raze", "razi",
+    "razo", "razu", "reba", "rebe", "rebi", "rebo", "rebu", "reca",
+    "rece", "reci", "reco", "recu", "reda", "rede", "redi", "redo",
+    "redu", "refa", "refe", "refi", "refo", "refu", "rega", "rege",
+    "regi", "rego", "regu", "reha", "rehe", "rehi", "reho", "rehu",
+    "reja", "reje", "reji", "rejo", "reju", "reka", "reke", "reki",
+    "reko", "reku", "rela", "rele", "reli", "relo", "relu", "rema",
+    "reme", "remi", "remo", "remu", "rena", "rene", "reni", "reno",
+    "renu", "repa", "repe", "repi", "repo", "repu", "rera", "rere",
+    "reri", "rero", "reru", "resa", "rese", "resi", "reso", "resu",
+    "reta", "rete", "reti", "reto", "retu", "reva", "reve", "revi",
+    "revo", "revu", "rewa", "rewe", "rewi", "rewo", "rewu", "reza",
+    "reze", "rezi", "rezo", "rezu", "riba", "ribe", "ribi", "ribo",
+    "ribu", "rica", "rice", "rici", "rico", "ricu", "rida", "ride",
+    "ridi", "rido", "ridu", "rifa", "rife", "rifi", "rifo", "rifu",
+    "riga", "rige", "rigi", "rigo", "rigu", "riha", "rihe", "rihi",
+    "riho", "rihu", "rija", "rije", "riji", "rijo", "riju", "rika",
+    "rike", "riki", "riko", "riku", "rila", "rile", "rili", "rilo",
+    "rilu", "rima", "rime", "rimi", "rimo", "rimu", "rina", "rine",
+    "rini", "rino", "rinu", "ripa", "ripe", "ripi", "ripo", "ripu",
+    "rira", "rire", "riri", "riro", "riru", "risa", "rise", "risi",
+    "riso", "risu", "rita", "rite", "riti", "rito", "ritu", "riva",
+    "rive", "rivi", "rivo", "rivu", "riwa", "riwe", "riwi", "riwo",
+    "riwu", "riza", "rize", "rizi", "rizo", "rizu", "roba", "robe",
+    "robi", "robo", "robu", "roca", "roce", "roci", "roco", "rocu",
+    "roda", "rode", "rodi", "rodo", "rodu", "rofa", "rofe", "rofi",
+    "rofo", "rofu", "roga", "roge", "rogi", "rogo", "rogu", "roha",
+    "rohe", "rohi", "roho", "rohu", "roja", "roje", "roji", "rojo",
+    "roju", "roka", "roke", "roki", "roko", "roku", "rola", "role",
+    "roli", "rolo", "rolu", "roma", "rome", "romi", "romo", "romu",
+    "rona", "rone", "roni", "rono", "ronu", "ropa", "rope", "ropi",
+    "ropo", "ropu", "rora", "rore", "rori", "roro", "roru", "rosa",
+    "rose", "rosi", "roso", "rosu", "rota", "rote", "roti", "roto",
+    "rotu", "rova", "rove", "rovi", "rovo", "rovu", "rowa", "rowe",
+    "rowi", "rowo", "rowu", "roza", "roze", "rozi", "rozo", "rozu",
+    "ruba", "rube", "rubi", "rubo", "rubu", "ruca", "ruce", "ruci",
+    "ruco", "rucu", "ruda", "rude", "rudi", "rudo", "rudu", "rufa",
+    "rufe", "rufi", "rufo", "rufu", "ruga", "ruge", "rugi", "rugo",
+    "rugu", "ruha", "ruhe", "ruhi", "ruho", "ruhu", "ruja", "ruje",
+    "ruji", "rujo", "ruju", "ruka", "ruke", "ruki", "ruko", "ruku",
+    "rula", "rule", "ruli", "rulo", "rulu", "ruma", "rume", "rumi",
+    "rumo", "rumu", "runa", "rune", "runi", "runo", "runu", "rupa",
+    "rupe", "rupi", "rupo", "rupu", "rura", "rure", "ruri", "ruro",
+    "ruru", "rusa", "ruse", "rusi", "ruso", "rusu", "ruta", "rute",
+    "ruti", "ruto", "rutu", "ruva", "ruve", "ruvi", "ruvo", "ruvu",
+    "ruwa", "ruwe", "ruwi", "ruwo", "ruwu", "ruza", "ruze", "ruzi",
+    "ruzo", "ruzu", "saba", "sabe", "sabi", "sabo", "sabu", "saca",
+    "sace", "saci", "saco", "sacu", "sada", "sade", "sadi", "sado",
+    "sadu", "safa", "safe", "safi", "safo", "safu", "saga", "sage",
+    "sagi", "sago", "sagu", "saha", "sahe", "sahi", "saho", "sahu",
+    "saja", "saje", "saji", "sajo", "saju", "saka", "sake", "saki",
+    "sako", "saku", "sala", "sale", "sali", "salo", "salu", "sama",
+    "same", "sami", "samo", "samu", "sana", "sane", "sani", "sano",
+    "sanu", "sapa", "sape", "sapi", "sapo", "sapu", "sara", "sare",
+    "sari", "saro", "saru", "sasa", "sase", "sasi", "saso", "sasu",
+    "sata", "sate", "sati", "sato", "satu", "sava", "save", "savi",
+    "savo", "savu", "sawa", "sawe", "sawi", "sawo", "sawu", "saza",
+    "saze", "sazi", "sazo", "sazu", "seba", "sebe", "sebi", "sebo",
+    "sebu", "seca", "sece", "seci", "seco", "secu", "seda", "sede",
+    "sedi", "sedo", "sedu", "sefa", "sefe", "sefi", "sefo", "sefu",
+    "sega", "sege", "segi", "sego", "segu", "seha", "sehe", "sehi",
+    "seho", "sehu", "seja", "seje", "seji", "sejo", "seju", "seka",
+    "seke", "seki", "seko", "seku", "sela", "sele", "seli", "selo",
+    "selu", "sema", "seme", "semi", "semo", "semu", "sena", "sene",
+    "seni", "seno", "senu", "sepa", "sepe", "sepi", "sepo", "sepu",
+    "sera", "sere", "seri", "sero", "seru", "sesa", "sese", "sesi",
+    "seso", "sesu", "seta", "sete", "seti", "seto", "setu", "seva",
+    "seve", "sevi", "sevo", "sevu", "sewa", "sewe", "sewi", "sewo",
+    "sewu", "seza", "seze", "sezi", "sezo", "sezu", "siba", "sibe",
+    "sibi", "sibo", "sibu", "sica", "sice", "sici", "sico", "sicu",
+    "sida", "side", "sidi", "sido", "sidu", "sifa", "sife", "sifi",
+    "sifo", "sifu", "siga", "sige", "sigi", "sigo", "sigu", "siha",
+    "sihe", "sihi", "siho", "sihu", "sija", "sije", "siji", "sijo",
+    "siju", "sika", "sike", "siki", "siko", "siku", "sila", "sile",
+    "sili", "silo", "silu", "sima", "sime", "simi", "simo", "simu",
+    "sina", "sine", "sini", "sino", "sinu", "sipa", "sipe", "sipi",
+    "sipo", "sipu", "sira", "sire", "siri", "siro", "siru", "sisa",
+    "sise", "sisi", "siso", "sisu", "sita", "site", "siti", "sito",
+    "situ", "siva", "sive", "sivi", "sivo", "sivu", "siwa", "siwe",
+    "siwi", "siwo", "siwu", "siza", "size", "sizi", "sizo", "sizu",
+    "soba", "sobe", "sobi", "sobo", "sobu", "soca", "soce", "soci",
+    "soco", "socu", "soda", "sode", "sodi", "sodo", "sodu", "sofa",
+    "sofe", "sofi", "sofo", "sofu", "soga", "soge", "sogi", "sogo",
+    "sogu", "soha", "sohe", "sohi", "soho", "sohu", "soja", "soje",
+    "soji", "sojo", "soju", "soka", "soke", "soki", "soko", "soku",
+    "sola", "sole", "soli", "solo", "solu", "soma", "some", "somi",
+    "somo", "somu", "sona", "sone", "soni", "sono", "sonu", "sopa",
+    "sope", "sopi", "sopo", "sopu", "sora", "sore", "sori", "soro",
+    "soru", "sosa", "sose", "sosi", "soso", "sosu", "sota", "sote",
+    "soti", "soto", "sotu", "sova", "sove", "sovi", "sovo", "sovu",
+    "sowa", "sowe", "sowi", "sowo", "sowu", "soza", "soze", "sozi",
+    "sozo", "sozu", "suba", "sube", "subi", "subo", "subu", "suca",
+    "suce", "suci", "suco", "sucu", "suda", "sude", "sudi", "sudo",
+    "sudu", "sufa", "sufe", "sufi", "sufo", "sufu", "suga", "suge",
+    "sugi", "sugo", "sugu", "suha", "suhe", "suhi", "suho", "suhu",
+    "suja", "suje", "suji", "sujo", "suju", "suka", "suke", "suki",
+    "suko", "suku", "sula", "sule", "suli", "sulo", "sulu", "suma",
+    "sume", "sumi", "sumo", "sumu", "suna", "sune", "suni", "suno",
+    "sunu", "supa", "supe", "supi", "supo", "supu", "sura", "sure",
+    "suri", "suro", "suru", "susa", "suse", "susi", "suso", "susu",
+    "suta", "sute", "suti", "suto", "sutu", "suva", "suve", "suvi",
+    "suvo", "suvu", "suwa", "suwe", "suwi", "suwo", "suwu", "suza",
+    "suze", "suzi", "suzo", "suzu", "taba", "tabe", "tabi", "tabo",
+    "tabu", "taca", "tace", "taci", "taco", "tacu", "tada", "tade",
+    "tadi", "tado", "tadu", "tafa", "tafe", "tafi", "tafo", "tafu",
+    "taga", "tage", "tagi", "tago", "tagu", "taha", "tahe", "tahi",
+    "taho", "tahu", "taja", "taje", "taji", "tajo", "taju", "taka",
+    "take", "taki", "tako", "taku", "tala", "tale", "tali", "talo",
+    "talu", "tama", "tame", "tami", "tamo", "tamu", "tana", "tane",
+    "tani", "tano", "tanu", "tapa", "tape", "tapi", "tapo", "tapu",
+    "tara", "tare", "tari", "taro", "taru", "tasa", "tase", "tasi",
+    "taso", "tasu", "tata", "tate", "tati", "tato", "tatu", "tava",
+    "tave", "tavi", "tavo", "tavu", "tawa", "tawe", "tawi", "tawo",
+    "tawu", "taza", "taze", "tazi", "tazo", "tazu", "teba", "tebe",
+    "tebi", "tebo", "tebu", "teca", "tece", "teci", "teco", "tecu",
+    "teda", "tede", "tedi", "tedo", "tedu", "tefa", "tefe", "tefi",
+    "tefo", "tefu", "tega", "tege", "tegi", "tego", "tegu", "teha",
+    "tehe", "tehi", "teho", "tehu", "teja", "teje", "teji", "tejo",
+    "teju", "teka", "teke", "teki", "teko", "teku", "tela", "tele",
+    "teli", "telo", "telu", "tema", "teme", "temi", "temo", "temu",
+    "tena", "tene", "teni", "teno", "tenu", "tepa", "tepe", "tepi",
+    "tepo", "tepu", "tera", "tere", "teri", "tero", "teru", "tesa",
+    "tese", "tesi", "teso", "tesu", "teta", "tete", "teti", "teto",
+    "tetu", "teva", "teve", "tevi", "tevo", "tevu", "tewa", "tewe",
+    "tewi", "tewo", "tewu", "teza", "teze", "tezi", "tezo", "tezu",
+    "tiba", "tibe", "tibi", "tibo", "tibu", "tica", "tice", "tici",
+    "tico", "ticu", "tida", "tide", "tidi", "tido", "tidu", "tifa",
+    "tife", "tifi", "tifo", "tifu", "tiga", "tige", "tigi", "tigo",
+    "tigu", "tiha", "tihe", "tihi", "tiho", "tihu", "tija", "tije",
+    "tiji", "tijo", "tiju", "tika", "tike", "tiki", "tiko", "tiku",
+    "tila", "tile", "tili", "tilo", "tilu", "tima", "time", "timi",
+    "timo", "timu", "tina", "tine", "tini", "tino", "tinu", "tipa",
+    "tipe", "tipi", "tipo", "tipu", "tira", "tire", "tiri", "tiro",
+    "tiru", "tisa", "tise", "tisi", "tiso", "tisu", "tita", "tite",
+    "titi", "tito", "titu", "tiva", "tive", "tivi", "tivo", "tivu",
+    "tiwa", "tiwe", "tiwi", "tiwo", "tiwu", "tiza", "tize", "tizi",
+    "tizo", "tizu", "toba", "tobe", "tobi", "tobo", "tobu", "toca",
+    "toce", "toci", "toco", "tocu", "toda", "tode", "todi", "todo",
+    "todu", "tofa", "tofe", "tofi", "tofo", "tofu", "toga", "toge",
+    "togi", "togo", "togu", "toha", "tohe", "tohi", "toho", "tohu",
+    "toja", "toje", "toji", "tojo", "toju", "toka", "toke", "toki",
+    "toko", "toku", "tola", "tole", "toli", "tolo", "tolu", "toma",
+    "tome", "tomi", "tomo", "tomu", "tona", "tone", "toni", "tono",
+    "tonu", "topa", "tope", "topi", "topo", "topu", "tora", "tore",
+    "tori", "toro", "toru", "tosa", "tose", "tosi", "toso", "tosu",
+    "tota", "tote", "toti", "toto", "totu", "tova", "tove", "tovi",
+    "tovo", "tovu", "towa", "towe", "towi", "towo", "towu", "toza",
+    "toze", "tozi", "tozo", "tozu", "tuba", "tube", "tubi", "tubo",
+    "tubu", "tuca", "tuce", "tuci", "tuco", "tucu", "tuda", "tude",
+    "tudi", "tudo", "tudu", "tufa", "tufe", "tufi", "tufo", "tufu",
+    "tuga", "tuge", "tugi", "tugo", "tugu", "tuha", "tuhe", "tuhi",
+    "tuho", "tuhu", "tuja", "tuje", "tuji", "tujo", "tuju", "tuka",
+    "tuke", "tuki", "tuko", "tuku", "tula", "tule", "tuli", "tulo",
+    "tulu", "tuma", "tume", "tumi", "tumo", "tumu", "tuna", "tune",
+    "tuni", "tuno", "tunu", "tupa", "tupe", "tupi", "tupo", "tupu",
+    "tura", "ture", "turi", "turo", "turu", "tusa", "tuse", "tusi",
+    "tuso", "tusu", "tuta", "tute", "tuti", "tuto", "tutu", "tuva",
+    "tuve", "tuvi", "tuvo", "tuvu", "tuwa", "tuwe", "tuwi", "tuwo",
+    "tuwu", "tuza", "tuze", "tuzi", "tuzo", "tuzu", "vaba", "vabe",
+    "vabi", "vabo", "vabu", "vaca", "vace", "vaci", "vaco", "vacu",
+    "vada", "vade", "vadi", "vado", "vadu", "vafa", "vafe", "vafi",
+    "vafo", "vafu", "vaga", "vage", "vagi", "vago", "vagu", "vaha",
+    "vahe", "vahi", "vaho", "vahu", "vaja", "vaje", "vaji", "vajo",
+    "vaju", "vaka", "vake", "vaki", "vako", "vaku", "vala", "vale",
+    "vali", "valo", "valu", "vama", "vame", "vami", "vamo", "vamu",
+    "vana", "vane", "vani", "vano", "vanu", "vapa", "vape", "vapi",
+    "vapo", "vapu", "vara", "vare", "vari", "varo", "varu", "vasa",
+    "vase", "vasi", "vaso", "vasu", "vata", "vate", "vati", "vato",
+    "vatu", "vava", "vave", "vavi", "vavo", "vavu", "vawa", "vawe",
+    "vawi", "vawo", "vawu", "vaza", "vaze", "vazi", "vazo", "vazu",
+    "veba", "vebe", "vebi", "vebo", "vebu", "veca", "vece", "veci",
+    "veco", "vecu", "veda", "vede", "vedi", "vedo", "vedu", "vefa",
+    "vefe", "vefi", "vefo", "vefu", "vega", "vege", "vegi", "vego",
+    "vegu", "veha", "vehe", "vehi", "veho", "vehu", "veja", "veje",
+    "veji", "vejo", "veju", "veka", "veke", "veki", "veko", "veku",
+    "vela", "vele", "veli", "velo", "velu", "vema", "veme", "vemi",
+    "vemo", "vemu", "vena", "vene", "veni", "veno", "venu", "vepa",
+    "vepe", "vepi", "vepo", "vepu", "vera", "vere", "veri", "vero",
+    "veru", "vesa", "vese", "vesi", "veso", "vesu", "veta", "vete",
+    "veti", "veto", "vetu", "veva", "veve", "vevi", "vevo", "vevu",
+    "vewa", "vewe", "vewi", "vewo", "vewu", "veza", "veze", "vezi",
+    "vezo", "vezu", "viba", "vibe", "vibi", "vibo", "vibu", "vica",
+    "vice", "vici", "vico", "vicu", "vida", "vide", "vidi", "vido",
+    "vidu", "vifa", "vife", "vifi", "vifo", "vifu", "viga", "vige",
+    "vigi", "vigo", "vigu", "viha", "vihe", "vihi", "viho", "vihu",
+    "vija", "vije", "viji", "vijo", "viju", "vika", "vike", "viki",
+    "viko", "viku", "vila", "vile", "vili", "vilo", "vilu", "vima",
+    "vime", "vimi", "vimo", "vimu", "vina", "vine", "vini", "vino",
+    "vinu", "vipa", "vipe", "vipi", "vipo", "vipu", "vira", "vire",
+    "viri", "viro", "viru", "visa", "vise", "visi", "viso", "visu",
+    "vita", "vite", "viti", "vito", "vitu", "viva", "vive", "vivi",
+    "vivo", "vivu", "viwa", "viwe", "viwi", "viwo", "viwu", "viza",
+    "vize", "vizi", "vizo", "vizu", "voba", "vobe", "vobi", "vobo",
+    "vobu", "voca", "voce", "voci", "voco", "vocu", "voda", "vode",
+    "vodi", "vodo", "vodu", "vofa", "vofe", "vofi", "vofo", "vofu",
+    "voga", "voge", "vogi", "vogo", "vogu", "voha", "vohe", "vohi",
+    "voho", "vohu", "voja", "voje", "voji", "vojo", "voju", "voka",
+    "voke", "voki", "voko", "voku", "vola", "vole", "voli", "volo",
+    "volu", "voma", "vome", "vomi", "vomo", "vomu", "vona", "vone",
+    "voni", "vono", "vonu", "vopa", "vope", "vopi", "vopo", "vopu",
+    "vora", "vore", "vori", "voro", "voru", "vosa", "vose", "vosi",
+    "voso", "vosu", "vota", "vote", "voti", "voto", "votu", "vova",
+    "vove", "vovi", "vovo", "vovu", "vowa", "vowe", "vowi", "vowo",
+    "vowu", "voza", "voze", "vozi", "vozo", "vozu", "vuba", "vube",
+    "vubi", "vubo", "vubu", "vuca", "vuce", "vuci", "vuco", "vucu",
+    "vuda", "vude", "vudi", "vudo", "vudu", "vufa", "vufe", "vufi",
+    "vufo", "vufu", "vuga", "vuge", "vugi", "vugo", "vugu", "vuha",
+    "vuhe", "vuhi", "vuho", "vuhu", "vuja", "vuje", "vuji", "vujo",
+    "vuju", "vuka", "vuke", "vuki", "vuko", "vuku", "vula", "vule",
+    "vuli", "vulo", "vulu", "vuma", "vume", "vumi", "vumo", "vumu",
+    "vuna", "vune", "vuni", "vuno", "vunu", "vupa", "vupe", "vupi",
+    "vupo", "vupu", "vura", "vure", "vuri", "vuro", "vuru", "vusa",
+    "vuse", "vusi", "vuso", "vusu", "vuta", "vute", "vuti", "vuto",
+    "vutu", "vuva", "vuve", "vuvi", "vuvo", "vuvu", "vuwa", "vuwe",
+    "vuwi", "vuwo", "vuwu", "vuza", "vuze", "vuzi", "vuzo", "vuzu",
+    "waba", "wabe", "wabi", "wabo", "wabu", "waca", "wace", "waci",
+    "waco", "wacu", "wada", "wade", "wadi", "wado", "wadu", "wafa",
+    "wafe", "wafi", "wafo", "wafu", "waga", "wage", "wagi", "wago",
+    "wagu", "waha", "wahe", "wahi", "waho", "wahu", "waja", "waje",
+    "waji", "wajo", "waju", "waka", "wake", "waki", "wako", "waku",
+    "wala", "wale", "wali", "walo", "walu", "wama", "wame", "wami",
+    "wamo", "wamu", "wana", "wane", "wani", "wano", "wanu", "wapa",
+    "wape", "wapi", "wapo", "wapu", "wara", "ware", "wari", "waro",
+    "waru", "wasa", "wase", "wasi", "waso", "wasu", "wata", "wate",
+    "wati", "wato", "watu", "wava", "wave", "wavi", "wavo", "wavu",
+    "wawa", "wawe", "wawi", "wawo", "wawu", "waza", "waze", "wazi",
+    "wazo", "wazu", "weba", "webe", "webi", "webo", "webu", "weca",
+    "wece", "weci", "weco", "wecu", "weda", "wede", "wedi", "wedo",
+    "wedu", "wefa", "wefe", "wefi", "wefo", "wefu", "wega", "wege",
+    "wegi", "wego", "wegu", "weha", "wehe", "wehi", "weho", "wehu",
+    "weja", "weje", "weji", "wejo", "weju", "weka", "weke", "weki",
+    "weko", "weku", "wela", "wele", "weli", "welo", "welu", "wema",
+    "weme", "wemi", "wemo", "wemu", "wena", "wene", "weni", "weno",
+    "wenu", "wepa", "wepe", "wepi", "wepo", "wepu", "wera", "were",
+    "weri", "wero", "weru", "wesa", "wese", "wesi", "weso", "wesu",
+    "weta", "wete", "weti", "weto", "wetu", "weva", "weve", "wevi",
+    "wevo", "wevu", "wewa", "wewe", "wewi", "wewo", "wewu", "weza",
+    "weze", "wezi", "wezo", "wezu", "wiba", "wibe", "wibi", "wibo",
+    "wibu", "wica", "wice", "wici", "wico", "wicu", "wida", "wide",
+    "widi", "wido", "widu", "wifa", "wife", "wifi", "wifo", "wifu",
+    "wiga", "wige", "wigi", "wigo", "wigu", "wiha", "wihe", "wihi",
+    "wiho", "wihu", "wija", "wije", "wiji", "wijo", "wiju", "wika",
+    "wike", "wiki", "wiko", "wiku", "wila", "wile", "wili", "wilo",
+    "wilu", "wima", "wime", "wimi", "wimo", "wimu", "wina", "wine",
+    "wini", "wino", "winu", "wipa", "wipe", "wipi", "wipo", "wipu",
+    "wira", "wire", "wiri", "wiro", "wiru", "wisa", "wise", "wisi",
+    "wiso", "wisu", "wita", "wite", "witi", "wito", "witu", "wiva",
+    "wive", "wivi", "wivo", "wivu", "wiwa", "wiwe", "wiwi", "wiwo",
+    "wiwu", "wiza", "wize", "wizi", "wizo", "wizu", "woba", "wobe",
+    "wobi", "wobo", "wobu", "woca", "woce", "woci", "woco", "wocu",
+    "woda", "wode", "wodi", "wodo", "wodu", "wofa", "wofe", "wofi",
+    "wofo", "wofu", "woga", "woge", "wogi", "wogo", "wogu", "woha",
+    "wohe", "wohi", "woho", "wohu", "woja", "woje", "woji", "wojo",
+    "woju", "woka", "woke", "woki", "woko", "woku", "wola", "wole",
+    "woli", "wolo", "wolu", "woma", "wome", "womi", "womo", "womu",
+    "wona", "wone", "woni", "wono", "wonu", "wopa", "wope", "wopi",
+    "wopo", "wopu", "wora", "wore", "wori", "woro", "woru", "wosa",
+    "wose", "wosi", "woso", "wosu", "wota", "wote", "woti", "woto",
+    "wotu", "wova", "wove", "wovi", "wovo", "wovu", "wowa", "wowe",
+    "wowi", "wowo", "wowu", "woza", "woze", "wozi", "wozo", "wozu",
+    "wuba", "wube", "wubi", "wubo", "wubu", "wuca", "wuce", "wuci",
+    "wuco", "wucu", "wuda", "wude", "wudi", "wudo", "wudu", "wufa",
+    "wufe", "wufi", "wufo", "wufu", "wuga", "wuge", "wugi", "wugo",
+    "wugu", "wuha", "wuhe", "wuhi", "wuho", "wuhu", "wuja", "wuje",
+    "wuji", "wujo", "wuju", "wuka", "wuke", "wuki", "wuko", "wuku",
+    "wula", "wule", "wuli", "wulo", "wulu", "wuma", "wume", "wumi",
+    "wumo", "wumu", "wuna", "wune", "wuni", "wuno", "wunu", "wupa",
+    "wupe", "wupi", "wupo", "wupu", "wura", "wure", "wuri", "wuro",
+    "wuru", "wusa", "wuse", "wusi", "wuso", "wusu", "wuta", "wute",
+    "wuti", "wuto", "wutu", "wuva", "wuve", "wuvi", "wuvo", "wuvu",
+    "wuwa", "wuwe", "wuwi", "wuwo", "wuwu", "wuza", "wuze", "wuzi",
+    "wuzo", "wuzu", "zaba", "zabe", "zabi", "zabo", "zabu", "zaca",
+    "zace", "zaci", "zaco", "zacu", "zada", "zade", "zadi", "zado",
+    "zadu", "zafa", "zafe", "zafi", "zafo", "zafu", "zaga", "zage",
+    "zagi", "zago", "zagu", "zaha", "zahe", "zahi", "zaho", "zahu",
+    "zaja", "zaje", "zaji", "zajo", "zaju", "zaka", "zake", "zaki",
+    "zako", "zaku", "zala", "zale", "zali", "zalo", "zalu", "zama",
+    "zame", "zami", "zamo", "zamu", "zana", "zane", "zani", "zano",
+    "zanu", "zapa", "zape", "zapi", "zapo", "zapu", "zara", "zare",
+    "zari", "zaro", "zaru", "zasa", "zase", "zasi", "zaso", "zasu",
+    "zata", "zate", "zati", "zato", "zatu", "zava", "zave", "zavi",
+    "zavo", "zavu", "zawa", "zawe", "zawi", "zawo", "zawu", "zaza",
+    "zaze", "zazi", "zazo", "zazu", "zeba", "zebe", "zebi", "zebo",
+    "zebu", "zeca", "zece", "zeci", "zeco", "zecu", "zeda", "zede",
+    "zedi", "zedo", "zedu", "zefa", "zefe", "zefi", "zefo", "zefu",
+    "zega", "zege", "zegi", "zego", "zegu", "zeha", "zehe", "zehi",
+    "zeho", "zehu", "zeja", "zeje", "zeji", "zejo", "zeju", "zeka",
+];